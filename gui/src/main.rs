@@ -137,14 +137,17 @@ impl App {
                 regen: 0,
                 card_templates: vec![],
                 skill_templates: vec![],
+                gold: 0,
             },
             opponent: PlayerTemplate {
                 health: 300,
                 regen: 0,
                 card_templates: vec![],
                 skill_templates: vec![],
+                gold: 0,
             },
             seed: None,
+            sandstorm: Default::default(),
         }
         .try_into()
         .unwrap();
@@ -641,7 +644,9 @@ impl App {
                         let winrate = 100.0 * summary.victories as f32 / self.sim_iterations as f32;
                         let loserate = 100.0 * summary.defeats as f32 / self.sim_iterations as f32;
                         let drawrate = 100.0
-                            * (summary.draw_timeout as f32 + summary.draw_simultaneous as f32)
+                            * (summary.draw_timeout as f32
+                                + summary.draw_simultaneous as f32
+                                + summary.draw_sandstorm as f32)
                             / self.sim_iterations as f32;
                         let time_taken_per_sim = self.sim_elapsed / self.sim_iterations as u32;
 
@@ -666,7 +671,7 @@ impl App {
                                 ui.label("Draw rate:");
                                 ui.label(format!(
                                     "{drawrate:.2}% ({}/{})",
-                                    summary.draw_timeout + summary.draw_simultaneous,
+                                    summary.draw_timeout + summary.draw_simultaneous + summary.draw_sandstorm,
                                     self.sim_iterations
                                 ));
                                 ui.end_row();
@@ -684,10 +689,22 @@ impl App {
 
                                 ui.label("Draws:");
                                 ui.label(
-                                    (summary.draw_timeout + summary.draw_simultaneous).to_string(),
+                                    (summary.draw_timeout
+                                        + summary.draw_simultaneous
+                                        + summary.draw_sandstorm)
+                                        .to_string(),
                                 );
                                 ui.end_row();
 
+                                ui.label("Sandstorm:");
+                                ui.label(format!(
+                                    "{} won, {} lost, {} drawn",
+                                    summary.sandstorm_victories,
+                                    summary.sandstorm_defeats,
+                                    summary.draw_sandstorm
+                                ));
+                                ui.end_row();
+
                                 ui.separator();
                                 ui.end_row();
 
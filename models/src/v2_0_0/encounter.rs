@@ -1,6 +1,6 @@
 use super::{Enchantment, TieredCard, TieredSkill};
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum EncounterDay {
     Event,
     Numeric(usize),
@@ -2,21 +2,49 @@ use regex::Regex;
 
 lazy_static::lazy_static! {
     pub static ref NUMERIC_REGEX: Regex = Regex::new(r"[-+]?\d*\.?\d+").unwrap();
+    pub static ref STATIC_MULTICAST_FOR_EACH: Regex = Regex::new(r"^(?:this has \+(\d+) multicast for each (.+?)|for each (.+?), this (?:has|gains) \+(\d+) multicast)\.$").unwrap();
+    pub static ref STATIC_REGEN_FOR_EACH: Regex = Regex::new(r"^you have \+?(\d+) regen for each (.+?)\.$").unwrap();
+    pub static ref STATIC_GRANT_CRIT_CHANCE: Regex = Regex::new(r"^(adjacent|your|enemy) ([\p{L} ]+?) have ([+-]\d+)% crit chance\.$").unwrap();
+    pub static ref STATIC_GRANT_CRIT_CHANCE_POSITIONAL: Regex = Regex::new(r"^(.+?) has \+(\d+)% crit chance\.$").unwrap();
+    pub static ref STATIC_GRANT_COOLDOWN_REDUCTION: Regex = Regex::new(r"^(adjacent|your other|your) ([\p{L} ]+?)(?: have their cooldowns?|'? cooldowns are) reduced by (\d+)%\.$").unwrap();
+    pub static ref CONDITIONAL_FIGHT_START: Regex = Regex::new(r"^if you have an? ([\p{L} ]+?), (at the start of each fight, .+)$").unwrap();
+    pub static ref CONDITIONAL_MODIFIER: Regex = Regex::new(r"^if (you have exactly one|both adjacent items are) ([\p{L} ]+?), (.+)$").unwrap();
+    pub static ref CONDITIONAL_MODIFIER_SUFFIX: Regex = Regex::new(r"^(this has .+?) if you have an? ([\p{L} ]+?)\.$").unwrap();
+    pub static ref CONDITIONAL_REDUCE_ITS_COOLDOWN: Regex = Regex::new(r"^reduce its cooldown by (\d+)%\.$").unwrap();
     pub static ref STATIC_WEAPON_DAMAGE: Regex = Regex::new(r"^your weapons deal \+(\d+) damage\.$").unwrap();
+    pub static ref WHEN_YOU_USE_POSITIONAL: Regex = Regex::new(r"^when you use ((?:the|your) [\p{L} ]+?), (.+)$").unwrap();
+    pub static ref WHEN_YOU_ACT: Regex = Regex::new(r"^when you ((?:haste|slow|freeze|charge|reload|burn|poison|heal|gain regen|shield|transform an? [a-z]+)(?:(?:, or |, | or )(?:haste|slow|freeze|charge|reload|burn|poison|heal|gain regen|shield|transform an? [a-z]+))*), (.+)$").unwrap();
+    pub static ref TIMED_EFFECT_POSITIONAL: Regex = Regex::new(r"^(haste|slow|freeze|charge) (.+?) (?:for )?(\d+(?:\.\d+)?) second(?:s|\(s\))?\.?$").unwrap();
+    pub static ref TIMED_EFFECT_ORDERED: Regex = Regex::new(r"^(haste|slow|freeze|charge) (?:the|your) (slowest|fastest|most valuable|highest value|highest damage) (enemy )?([\p{L} ]*?) ?items? (?:for )?(\d+(?:\.\d+)?) second(?:s|\(s\))?\.?$").unwrap();
     pub static ref HASTE_N_FOR_M: Regex = Regex::new(r"^haste (\d+) items? for (\d+) second\(s\)\.$").unwrap();
     pub static ref SLOW_N_FOR_M: Regex = Regex::new(r"^slow (\d+) items? for (\d+) second\(s\)\.$").unwrap();
     pub static ref FREEZE_N_FOR_M: Regex = Regex::new(r"^freeze\s+(\d+)\s+item(?:s|\(s\))?\s+for\s+(\d+)\s+second(?:s|\(s\))?\.$").unwrap();
     pub static ref FREEZE_N_FOR_M_OF_SIZE: Regex = Regex::new(r"^freeze\s+(\d+)\s+(small|medium|large)\s+item(?:s|\(s\))?\s+for\s+(\d+)\s+second(?:s|\(s\))?\.$").unwrap();
     pub static ref EFFECT_REDUCE_CD_FLAT: Regex = Regex::new(r"^reduce this item's cooldown by (\d+) second.s. for the fight\.?$").unwrap();
+    pub static ref EFFECT_INCREASE_CD_FLAT: Regex = Regex::new(r"^increase this item's cooldown by (\d+) second.s. for the fight\.?$").unwrap();
+    pub static ref EFFECT_REDUCE_CD_PERCENTAGE: Regex = Regex::new(r"^reduce this item's cooldown by (\d+)% for the fight\.?$").unwrap();
+    pub static ref EFFECT_REDUCE_OTHER_CD_PERCENTAGE: Regex = Regex::new(r"^reduce the cooldown of your other items by (\d+)% for (?:the|this) fight\.?$").unwrap();
     pub static ref EFFECT_DEAL_DAMAGE_WEIRD: Regex = Regex::new(r"^deal damage (\d+)\.?$").unwrap(); // TODO
     pub static ref EFFECT_GET_ITEMS_REGEX: Regex = Regex::new(r"^get\s+(a|\d+)\s+([\p{L} ]+)\.?$").unwrap();
     pub static ref EFFECT_GET_TAG_CONDITIONAL_ITEMS_REGEX: Regex = Regex::new(r"^get a ([\p{L} ]+). if you have a ([\p{L} ]+), get a second ([\p{L} ]+)\.?").unwrap();
     pub static ref EFFECT_GET_TRIPLE_SINGULAR_ITEMS_REGEX: Regex = Regex::new(r"^get a ([\p{L} ]+), ([\p{L} ]+) and ([\p{L} ]+)\.?$").unwrap();
     pub static ref EFFECT_GAIN_PERMANENT_MAX_HP: Regex = Regex::new(r"^permanently gain (\d+) max health\.?$").unwrap();
     pub static ref EFFECT_SPEND_GOLD_FOR_EFFECT: Regex = Regex::new(r"^spend (\d+) gold to ([\p{L} ]+)\.?$").unwrap();
+    pub static ref EFFECT_CHARGE_THIS: Regex = Regex::new(r"^charge this (\d+(?:\.\d+)?) second(?:s|\(s\))?\.?$").unwrap();
+    pub static ref EFFECT_CHARGE_ADJACENT: Regex = Regex::new(r"^charge adjacent items (\d+(?:\.\d+)?) second(?:s|\(s\))?\.?$").unwrap();
+    pub static ref EFFECT_CHARGE_N_ITEMS: Regex = Regex::new(r"^charge (a|an|\d+) ([\p{L}\-() ]+?) (?:for )?(\d+(?:\.\d+)?) second(?:s|\(s\))?\.?$").unwrap();
+    pub static ref EFFECT_RELOAD_THIS_N_AMMO: Regex = Regex::new(r"^reload this (\d+) ammo\.?$").unwrap();
+    pub static ref EFFECT_RELOAD_N_ITEMS: Regex = Regex::new(r"^reload (an|\d+) items?\.?$").unwrap();
+    pub static ref EFFECT_RELOAD_N_AMMO_ITEMS_M_AMMO: Regex = Regex::new(r"^reload (\d+) ammo items? (\d+) ammo\.?$").unwrap();
+    pub static ref EFFECT_RELOAD_ADJACENT_AMMO_ITEMS_M_AMMO: Regex = Regex::new(r"^reload adjacent ammo items (\d+) ammo\.?$").unwrap();
+    pub static ref EFFECT_GAIN_STAT_FOR_THE_FIGHT: Regex = Regex::new(r"^(this|adjacent|your other|your) ([\p{L}\- ]*?) ?gains? \+(\d+) (damage|shield) for the fight\.?$").unwrap();
+    pub static ref EFFECT_GAIN_SHIELD_AND_DAMAGE_FOR_THE_FIGHT: Regex = Regex::new(r"^your shield items gain \+(\d+) shield and your weapons \+(\d+) damage for the fight\.?$").unwrap();
+    pub static ref EFFECT_DESTROY_N_ITEMS: Regex = Regex::new(r"^destroy (an?|\d+) ([\p{L} ]*?) ?(?:enemy )?items?(?: for the fight)?\.?$").unwrap();
+    pub static ref EFFECT_USE_ITEM: Regex = Regex::new(r"^use (a|an|\d+) ([\p{L}\- ()]+?)\.?$").unwrap();
     pub static ref EFFECT_THIS_GAINS_MAX_AMMO: Regex = Regex::new(r"^this gains (\d+) max ammo\.?$").unwrap();
     pub static ref EFFECT_POISON_SELF: Regex = Regex::new(r"^poison yourself (\d+)\.?$").unwrap();
     pub static ref EFFECT_UPGRADE_RANDOM_PIGGLE: Regex = Regex::new(r"^upgrade a random piggle\.?$").unwrap();
+    pub static ref EFFECT_TRANSFORM_INTO_COPY: Regex = Regex::new(r"^transform into a (bronze|silver|gold|diamond) copy of (another [\p{L}, -]+? you have|the [\p{L}, -]+? to the (?:left|right) of this) for the fight\.?$").unwrap();
     pub static ref EFFECT_GAIN_GOLD: Regex = Regex::new(r"^gain (\d+) gold\.?$").unwrap();
     pub static ref EFFECT_UPGRADE_LOWER_TIER_TAGGED: Regex = Regex::new(r"^upgrade a ([\p{L} ]+) of a lower tier\.?$").unwrap();
     pub static ref EFFECT_BURN_FROM_DAMAGE: Regex = Regex::new(r"burn equal to (\d+)% of this item's damage.").unwrap();
@@ -25,6 +53,8 @@ lazy_static::lazy_static! {
     pub static ref EFFECT_SHIELD_FROM_DAMAGE: Regex = Regex::new(r"shield equal to (\d+)% of this item's damage.").unwrap();
     pub static ref EFFECT_SHIELD_FROM_DAMAGE_FULL: Regex = Regex::new(r"shield equal to this item's damage.").unwrap();
     pub static ref EFFECT_POISON_FROM_DAMAGE: Regex = Regex::new(r"poison equal to (\d+)% of this item's damage.").unwrap();
+    pub static ref EFFECT_EQUAL_TO_PLAYER_PROPERTY: Regex = Regex::new(r"^(deal damage|heal|shield|burn|poison|gain regen) equal to (?:(\d+)% of )?(your|your enemy's) (max health|current health|health|shield|burn|poison|regen|gold)(?: for the fight)?\.?$").unwrap();
+    pub static ref EFFECT_FOR_EACH: Regex = Regex::new(r"^(deal|burn|poison|shield|heal) (\d+(?:\.\d+)?)(?: damage)? for each (.+?)\.?$").unwrap();
     pub static ref EFFECT_DEAL_DAMAGE: Regex = Regex::new(r"^deal (\d+) damage\.?$").unwrap();
     pub static ref EFFECT_BURN: Regex = Regex::new(r"^burn (\d+)\.?$").unwrap();
     pub static ref EFFECT_POISON: Regex = Regex::new(r"^poison (\d+)\.?$").unwrap();
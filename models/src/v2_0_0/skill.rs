@@ -1,4 +1,4 @@
-use super::{CardCombatEncounter, Hero, PackId, Tag, Tier, TieredValue, Tooltip};
+use super::{Card, CardCombatEncounter, Hero, PackId, Size, Tag, Tier, TieredValue, Tooltip};
 
 #[derive(Debug, Clone)]
 pub struct Skill {
@@ -20,3 +20,32 @@ pub struct TieredSkill {
     pub skill: Skill,
     pub tier: Tier,
 }
+
+/// Skills fight alongside the items of their owner, so the simulation treats them as cards that
+/// take up no board space. The size is a placeholder: the simulation never reads the size or the
+/// value of a skill.
+impl From<Skill> for Card {
+    fn from(value: Skill) -> Self {
+        Card {
+            id: value.id,
+            name: value.name,
+            starting_tier: value.starting_tier,
+            tiers: TieredValue {
+                bronze: value.tiers.bronze.into(),
+                silver: value.tiers.silver.into(),
+                gold: value.tiers.gold.into(),
+                diamond: value.tiers.diamond.into(),
+                legendary: value.tiers.legendary.into(),
+            },
+            tags: value.tags.into(),
+            hidden_tags: value.hidden_tags.into(),
+            custom_tags: value.custom_tags.into(),
+            size: Size::Small,
+            heroes: value.heroes.into(),
+            enchantments: [].into(),
+            unified_tooltips: value.unified_tooltips.into(),
+            pack_id: value.pack_id,
+            combat_encounters: value.combat_encounters.into(),
+        }
+    }
+}
@@ -40,3 +40,18 @@ impl Tier {
         }
     }
 }
+
+impl TryFrom<&str> for Tier {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.to_lowercase().as_str() {
+            "bronze" => Ok(Tier::Bronze),
+            "silver" => Ok(Tier::Silver),
+            "gold" => Ok(Tier::Gold),
+            "diamond" => Ok(Tier::Diamond),
+            "legendary" => Ok(Tier::Legendary),
+            tier => anyhow::bail!("invalid tier {tier}"),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use crate::v2_0_0::Tag;
+
+/// A kind of action a player performs, as in "when you haste, slow, poison or burn".
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Action {
+    Haste,
+    Slow,
+    Freeze,
+    Charge,
+    Reload,
+    Burn,
+    Poison,
+    Heal,
+    Regen,
+    Shield,
+    /// Transforming an item with the tag, as in "when you transform a reagent"
+    Transform(Tag),
+}
+
+impl Action {
+    /// Parses a single verb such as `"slow"`, `"gain regen"` or `"transform a potion"`.
+    pub fn from_verb(s: &str) -> Option<Self> {
+        match s.trim() {
+            "haste" => Some(Action::Haste),
+            "slow" => Some(Action::Slow),
+            "freeze" => Some(Action::Freeze),
+            "charge" => Some(Action::Charge),
+            "reload" => Some(Action::Reload),
+            "burn" => Some(Action::Burn),
+            "poison" => Some(Action::Poison),
+            "heal" => Some(Action::Heal),
+            "gain regen" => Some(Action::Regen),
+            "shield" => Some(Action::Shield),
+            s => s
+                .strip_prefix("transform a ")
+                .or_else(|| s.strip_prefix("transform an "))
+                .and_then(|tag| Tag::from_str(tag).ok())
+                .map(Action::Transform),
+        }
+    }
+
+    /// Parses a list of verbs such as `"heal or gain regen"` or
+    /// `"haste, slow, freeze, poison, or burn"`.
+    pub fn from_verbs(s: &str) -> Option<Vec<Self>> {
+        s.split(',')
+            .flat_map(|s| s.split(" or "))
+            .map(|s| s.trim().trim_start_matches("or "))
+            .filter(|s| !s.is_empty())
+            .map(Action::from_verb)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for Action {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Transform(tag) => write!(f, "Action::Transform(Tag::{tag:?})"),
+            _ => write!(f, "Action::{self:?}"),
+        }
+    }
+}
@@ -1,8 +1,10 @@
+use std::str::FromStr;
+
 use crate::v2_0_0::{EffectValue, Percentage, Tag};
 
 use super::{
-    CardDerivedProperty, CardTarget, Condition, DerivedValue, Effect, EffectEvent, GlobalEvent,
-    Modifier, PlayerTarget, TargetCondition,
+    Action, CardDerivedProperty, CardTarget, Condition, DerivedValue, Effect, EffectEvent,
+    GlobalEvent, Modifier, PlayerTarget, TargetCondition, TargetSelection,
 };
 
 pub fn parse_numeric<T: std::str::FromStr>(cooldown_str: &str) -> anyhow::Result<T>
@@ -32,7 +34,9 @@ pub enum Tooltip {
 impl std::fmt::Display for Tooltip {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
-            Tooltip::Conditional(i, j) => write!(f, "Tooltip::Conditional({i}, {j})"),
+            Tooltip::Conditional(i, j) => {
+                write!(f, "Tooltip::Conditional({i}, Box::new({j}))")
+            }
             Tooltip::When(i) => write!(f, "Tooltip::When({i})"),
             Tooltip::SellsForGold => write!(f, "Tooltip::SellsForGold"),
             Tooltip::StaticModifier(i) => write!(f, "Tooltip::StaticModifier({i})"),
@@ -62,13 +66,25 @@ impl Tooltip {
     }
 
     fn from_first_time(tooltip: &str) -> anyhow::Result<Tooltip> {
-        let effect_event = if let Some(rest) =
-            tooltip.strip_prefix("the first time you fall below half health each fight, ")
-        {
+        let effect_event = if let Some(rest) = tooltip
+            .strip_prefix("the first time you fall below half health each fight, ")
+            .or_else(|| {
+                tooltip.strip_prefix("the first time you fall below half health in a fight, ")
+            })
+            .or_else(|| {
+                tooltip.strip_prefix("the first time you fall below 50% health each fight, ")
+            }) {
             EffectEvent::OnFirstTime(
                 GlobalEvent::PlayerFallsBelowHpPercentage(50.0),
                 Effect::from_tooltip_str(rest),
             )
+        } else if let Some(rest) =
+            tooltip.strip_prefix("the first time your enemy falls below half health each fight, ")
+        {
+            EffectEvent::OnFirstTime(
+                GlobalEvent::EnemyFallsBelowHpPercentage(50.0),
+                Effect::from_tooltip_str(rest),
+            )
         } else {
             anyhow::bail!("invalid first time event: '{tooltip}'");
         };
@@ -109,15 +125,56 @@ impl Tooltip {
             EffectEvent::OnWinVersusHero(Effect::from_tooltip_str(rest))
         } else if let Some(rest) = tooltip.strip_prefix("when you use a weapon,") {
             EffectEvent::OnCardUsed(
-                TargetCondition::HasOwner(PlayerTarget::Player),
+                TargetCondition::HasOwner(PlayerTarget::Player)
+                    & TargetCondition::HasTag(Tag::Weapon),
                 Effect::from_tooltip_str(rest),
             )
+        } else if let Some((condition, rest)) = crate::v2_0_0::re::WHEN_YOU_USE_POSITIONAL
+            .captures(tooltip)
+            .and_then(|c| {
+                Some((
+                    TargetCondition::from_position(c.get(1)?.as_str())?,
+                    c.get(2)?.as_str(),
+                ))
+            })
+        {
+            EffectEvent::OnCardUsed(condition, Effect::from_tooltip_str(rest))
+        } else if let Some((actions, rest)) = crate::v2_0_0::re::WHEN_YOU_ACT
+            .captures(tooltip)
+            .and_then(|c| Some((Action::from_verbs(c.get(1)?.as_str())?, c.get(2)?.as_str())))
+        {
+            EffectEvent::OnAction(actions, Effect::from_tooltip_str(rest))
         } else {
             anyhow::bail!("invalid conditional effect: '{tooltip}'")
         };
         Ok(Tooltip::When(effect_event))
     }
 
+    /// Wraps a parsed tooltip so that it only applies while `condition` holds. Raw tooltips stay
+    /// unparsed.
+    fn into_conditional(self, condition: Condition) -> Option<Tooltip> {
+        match self {
+            Tooltip::Raw(_) => None,
+            Tooltip::StaticModifier(modifier) => {
+                Some(Tooltip::ConditionalModifier(condition, modifier))
+            }
+            tooltip => Some(Tooltip::Conditional(condition, Box::new(tooltip))),
+        }
+    }
+
+    /// Parses the rest of "if you have exactly one <tag>, ...", where "it" refers to that card.
+    fn from_exactly_one(tag: Tag, value: &str) -> Tooltip {
+        if let Some(capture) = crate::v2_0_0::re::CONDITIONAL_REDUCE_ITS_COOLDOWN.captures(value) {
+            if let Some(Ok(reduction)) = capture.get(1).map(|r| r.as_str().parse::<f64>()) {
+                return Tooltip::StaticModifier(Modifier::GrantCooldownReduction(
+                    TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(tag),
+                    Percentage::from_percentage_value(reduction),
+                ));
+            }
+        }
+        Tooltip::from_str(value)
+    }
+
     pub fn from_or_raw<T: TryInto<Tooltip> + ToString>(value: T) -> Self {
         let copy = value.to_string();
         let tooltip = value.try_into().unwrap_or(Tooltip::Raw(copy));
@@ -154,13 +211,6 @@ impl Tooltip {
                 .map(Tooltip::StaticModifier)
                 .unwrap_or(Tooltip::Raw(value.to_string()));
         }
-        if let Some(rest) = value.strip_prefix("use") {
-            TargetCondition::from_str(rest);
-            return parse_numeric(value)
-                .map(Modifier::Multicast)
-                .map(Tooltip::StaticModifier)
-                .unwrap_or(Tooltip::Raw(value.to_string()));
-        }
         if value.starts_with("crit chance") {
             return parse_numeric(value)
                 .map(Percentage::from_percentage_value)
@@ -175,6 +225,7 @@ impl Tooltip {
                         CardTarget(
                             usize::MAX,
                             TargetCondition::HasOwner(PlayerTarget::Opponent),
+                            TargetSelection::Random,
                         ),
                         v,
                     )
@@ -197,38 +248,125 @@ impl Tooltip {
                 .map(Tooltip::When)
                 .unwrap_or(Tooltip::Raw(value.to_string()));
         }
+        if let Some(capture) = crate::v2_0_0::re::CONDITIONAL_FIGHT_START.captures(value) {
+            if let (Some(kind_str), Some(rest)) = (capture.get(1), capture.get(2)) {
+                if let (Some(condition), Ok(tooltip)) = (
+                    Condition::has_card_of_kind(kind_str.as_str()),
+                    Tooltip::from_at_the_start(rest.as_str()),
+                ) {
+                    return Tooltip::Conditional(condition, Box::new(tooltip));
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::CONDITIONAL_MODIFIER.captures(value) {
+            if let (Some(kind_str), Some(tag_str), Some(rest)) =
+                (capture.get(1), capture.get(2), capture.get(3))
+            {
+                if let Ok(tag) = Tag::from_str(tag_str.as_str()) {
+                    let (condition, tooltip) = match kind_str.as_str() {
+                        "you have exactly one" => (
+                            Condition::HasExactlyOneCardOfTag(tag),
+                            Tooltip::from_exactly_one(tag, rest.as_str()),
+                        ),
+                        _ => (
+                            Condition::AdjacentCardsHaveTag(tag),
+                            Tooltip::from_str(rest.as_str()),
+                        ),
+                    };
+                    if let Some(tooltip) = tooltip.into_conditional(condition) {
+                        return tooltip;
+                    }
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::CONDITIONAL_MODIFIER_SUFFIX.captures(value) {
+            if let (Some(modifier_str), Some(kind_str)) = (capture.get(1), capture.get(2)) {
+                if let Some(tooltip) =
+                    Condition::has_card_of_kind(kind_str.as_str()).and_then(|condition| {
+                        Tooltip::from_str(&format!("{}.", modifier_str.as_str()))
+                            .into_conditional(condition)
+                    })
+                {
+                    return tooltip;
+                }
+            }
+        }
         if value.starts_with("at the start of") {
             return Tooltip::from_at_the_start(value).unwrap_or(Tooltip::Raw(value.to_string()));
         }
         if value == "sells for gold." {
             return Tooltip::SellsForGold;
         }
-        if value == "this deals double crit damage." {
+        if value == "this deals double crit damage." || value == "this has double crit damage." {
             return Tooltip::StaticModifier(Modifier::DoubleCritDamage);
         }
-        if value.starts_with("your other weapons gain") && value.ends_with("damage for the fight.")
+        if value == "this deals quadruple crit damage." {
+            return Tooltip::StaticModifier(Modifier::QuadrupleCritDamage);
+        }
+        if let Some(capture) = crate::v2_0_0::re::STATIC_GRANT_CRIT_CHANCE.captures(value) {
+            if let (Some(owner_str), Some(kind_str), Some(chance_str)) =
+                (capture.get(1), capture.get(2), capture.get(3))
+            {
+                let owner = match owner_str.as_str() {
+                    "adjacent" => TargetCondition::Adjacent,
+                    "enemy" => TargetCondition::HasOwner(PlayerTarget::Opponent),
+                    _ => TargetCondition::HasOwner(PlayerTarget::Player),
+                };
+                let condition = match TargetCondition::from_item_kind(kind_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(owner),
+                    Some(condition) => Some(owner & condition),
+                    None => None,
+                };
+                if let (Some(condition), Ok(chance)) =
+                    (condition, chance_str.as_str().parse::<f64>())
+                {
+                    return Tooltip::StaticModifier(Modifier::GrantCritChance(
+                        condition,
+                        Percentage::from_percentage_value(chance),
+                    ));
+                }
+            }
+        }
+        if let Some(capture) =
+            crate::v2_0_0::re::STATIC_GRANT_CRIT_CHANCE_POSITIONAL.captures(value)
         {
-            return parse_numeric(value)
-                .map(|v: u32| {
-                    Effect::IncreaseDamage(
-                        CardTarget(
-                            usize::MAX,
-                            TargetCondition::HasOwner(PlayerTarget::Player)
-                                & TargetCondition::HasTag(Tag::Weapon),
-                        ),
-                        EffectValue::Flat(v),
-                    )
-                })
-                .map(EffectEvent::OnCooldown)
-                .map(Tooltip::When)
-                .unwrap_or(Tooltip::Raw(value.to_string()));
+            if let (Some(target_str), Some(chance_str)) = (capture.get(1), capture.get(2)) {
+                if let (Some(condition), Ok(chance)) = (
+                    TargetCondition::from_position(target_str.as_str()),
+                    chance_str.as_str().parse::<f64>(),
+                ) {
+                    return Tooltip::StaticModifier(Modifier::GrantCritChance(
+                        condition,
+                        Percentage::from_percentage_value(chance),
+                    ));
+                }
+            }
         }
-        if let Some(rest) = value.strip_prefix("use ") {
-            let effect = Effect::UseCard(CardTarget(
-                1,
-                TargetCondition::from_str(&rest) & TargetCondition::HasOwner(PlayerTarget::Player),
-            ));
-            return Tooltip::When(EffectEvent::OnCooldown(effect));
+        if let Some(capture) = crate::v2_0_0::re::STATIC_GRANT_COOLDOWN_REDUCTION.captures(value) {
+            if let (Some(owner_str), Some(kind_str), Some(reduction_str)) =
+                (capture.get(1), capture.get(2), capture.get(3))
+            {
+                let owner = match owner_str.as_str() {
+                    "adjacent" => TargetCondition::Adjacent,
+                    "your other" => {
+                        TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf
+                    }
+                    _ => TargetCondition::HasOwner(PlayerTarget::Player),
+                };
+                let condition = match TargetCondition::from_item_kind(kind_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(owner),
+                    Some(condition) => Some(owner & condition),
+                    None => None,
+                };
+                if let (Some(condition), Ok(reduction)) =
+                    (condition, reduction_str.as_str().parse::<f64>())
+                {
+                    return Tooltip::StaticModifier(Modifier::GrantCooldownReduction(
+                        condition,
+                        Percentage::from_percentage_value(reduction),
+                    ));
+                }
+            }
         }
         if value.starts_with("when") {
             return Tooltip::from_when(value).unwrap_or(Tooltip::Raw(value.to_string()));
@@ -242,96 +380,63 @@ impl Tooltip {
         if value == "this cannot be frozen, slowed or destroyed." {
             return Tooltip::StaticModifier(Modifier::Radiant);
         }
+        if value == "lifesteal" {
+            return Tooltip::StaticModifier(Modifier::Lifesteal);
+        }
         if value == "+50% crit chance" {
             return Tooltip::StaticModifier(Modifier::CritChance(
                 Percentage::from_percentage_value(50.0),
             ));
         }
         if value == "this has +1 multicast." {
-            return Tooltip::StaticModifier(Modifier::Multicast(1));
-        }
-        if value == "shield equal to the value of the adjacent items." {
-            let todo = true; //TODO: change to percentage
-            return Tooltip::When(EffectEvent::OnCooldown(Effect::Shield(
-                PlayerTarget::Player,
-                DerivedValue::FromCard(
-                    CardTarget(2, TargetCondition::Adjacent),
-                    CardDerivedProperty::Value,
-                    1.0,
-                ),
+            return Tooltip::StaticModifier(Modifier::IncreasedMulticast(DerivedValue::Constant(
+                1,
             )));
         }
-        if let Some(capture) = crate::v2_0_0::re::HASTE_N_FOR_M.captures(value) {
-            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
-                match (
-                    n_str.as_str().parse::<usize>(),
-                    m_str.as_str().parse::<f64>(),
+        if let Some(capture) = crate::v2_0_0::re::STATIC_MULTICAST_FOR_EACH.captures(value) {
+            // The count comes either after "for each" or before the multicast it grants
+            if let (Some(n_str), Some(counted_str)) = (
+                capture.get(1).or(capture.get(4)),
+                capture.get(2).or(capture.get(3)),
+            ) {
+                if let (Ok(n), Some(condition)) = (
+                    n_str.as_str().parse::<u32>(),
+                    TargetCondition::from_for_each(counted_str.as_str()),
                 ) {
-                    (Ok(n), Ok(m)) => {
-                        return Tooltip::When(EffectEvent::OnCooldown(Effect::Haste(
-                            CardTarget(n, TargetCondition::HasOwner(PlayerTarget::Player)),
-                            m,
-                        )));
-                    }
-                    _ => {}
-                }
-            }
-        }
-        if let Some(capture) = crate::v2_0_0::re::SLOW_N_FOR_M.captures(value) {
-            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
-                match (
-                    n_str.as_str().parse::<usize>(),
-                    m_str.as_str().parse::<f64>(),
-                ) {
-                    (Ok(n), Ok(m)) => {
-                        return Tooltip::When(EffectEvent::OnCooldown(Effect::Slow(
-                            CardTarget(n, TargetCondition::HasOwner(PlayerTarget::Opponent)),
-                            m,
-                        )));
-                    }
-                    _ => {}
+                    return Tooltip::StaticModifier(Modifier::IncreasedMulticast(
+                        DerivedValue::FromCard(
+                            CardTarget(usize::MAX, condition, TargetSelection::Random),
+                            CardDerivedProperty::Count,
+                            n as f32,
+                        ),
+                    ));
                 }
             }
         }
-        if let Some(capture) = crate::v2_0_0::re::FREEZE_N_FOR_M.captures(value) {
-            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
-                match (
-                    n_str.as_str().parse::<usize>(),
-                    m_str.as_str().parse::<f64>(),
+        if let Some(capture) = crate::v2_0_0::re::STATIC_REGEN_FOR_EACH.captures(value) {
+            if let (Some(n_str), Some(counted_str)) = (capture.get(1), capture.get(2)) {
+                if let (Ok(n), Some(condition)) = (
+                    n_str.as_str().parse::<u32>(),
+                    TargetCondition::from_for_each(counted_str.as_str()),
                 ) {
-                    (Ok(n), Ok(m)) => {
-                        return Tooltip::When(EffectEvent::OnCooldown(Effect::Freeze(
-                            CardTarget(n, TargetCondition::HasOwner(PlayerTarget::Opponent)),
-                            m,
-                        )));
-                    }
-                    _ => {}
+                    return Tooltip::StaticModifier(Modifier::Regen(DerivedValue::FromCard(
+                        CardTarget(usize::MAX, condition, TargetSelection::Random),
+                        CardDerivedProperty::Count,
+                        n as f32,
+                    )));
                 }
             }
         }
-        if let Some(capture) = crate::v2_0_0::re::FREEZE_N_FOR_M_OF_SIZE.captures(value) {
-            if let (Some(n_str), Some(size_str), Some(m_str)) =
-                (capture.get(1), capture.get(2), capture.get(3))
-            {
-                match (
-                    n_str.as_str().parse::<usize>(),
-                    m_str.as_str().parse::<f64>(),
-                    size_str.as_str().try_into(),
-                ) {
-                    (Ok(n), Ok(m), Ok(size)) => {
-                        return Tooltip::When(EffectEvent::OnCooldown(Effect::Freeze(
-                            CardTarget(
-                                n,
-                                TargetCondition::HasOwner(PlayerTarget::Opponent)
-                                    & TargetCondition::HasSize(size)
-                                    & TargetCondition::HasCooldown,
-                            ),
-                            m,
-                        )));
-                    }
-                    _ => {}
-                }
-            }
+        if value == "shield equal to the value of the adjacent items." {
+            let todo = true; //TODO: change to percentage
+            return Tooltip::When(EffectEvent::OnCooldown(Effect::Shield(
+                PlayerTarget::Player,
+                DerivedValue::FromCard(
+                    CardTarget(2, TargetCondition::Adjacent, TargetSelection::Random),
+                    CardDerivedProperty::Value,
+                    1.0,
+                ),
+            )));
         }
         if let Some(capture) = crate::v2_0_0::re::STATIC_WEAPON_DAMAGE.captures(value) {
             if let Some(damage_str) = capture.get(1) {
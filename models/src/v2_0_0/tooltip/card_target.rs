@@ -1,7 +1,39 @@
 use super::TargetCondition;
 
+/// How the cards an effect applies to are picked among the cards matching its condition.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TargetSelection {
+    #[default]
+    Random,
+    LowestCooldown,
+    HighestCooldown,
+    HighestValue,
+    HighestDamage,
+    Leftmost,
+}
+
+impl TargetSelection {
+    /// Parses superlatives such as `"slowest"` or `"highest value"`.
+    pub fn from_superlative(s: &str) -> Option<Self> {
+        match s.trim() {
+            "slowest" => Some(TargetSelection::HighestCooldown),
+            "fastest" => Some(TargetSelection::LowestCooldown),
+            "most valuable" | "highest value" => Some(TargetSelection::HighestValue),
+            "highest damage" => Some(TargetSelection::HighestDamage),
+            "leftmost" => Some(TargetSelection::Leftmost),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for TargetSelection {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "TargetSelection::{self:?}")
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
-pub struct CardTarget(pub usize, pub TargetCondition);
+pub struct CardTarget(pub usize, pub TargetCondition, pub TargetSelection);
 
 impl CardTarget {
     pub fn number_of_targets(&self) -> usize {
@@ -11,10 +43,14 @@ impl CardTarget {
     pub fn target_condition(&self) -> &TargetCondition {
         &self.1
     }
+
+    pub fn selection(&self) -> TargetSelection {
+        self.2
+    }
 }
 
 impl std::fmt::Display for CardTarget {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "CardTarget({}, {})", self.0, self.1)
+        write!(f, "CardTarget({}, {}, {})", self.0, self.1, self.2)
     }
 }
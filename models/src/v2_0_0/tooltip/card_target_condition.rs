@@ -1,4 +1,6 @@
-use crate::v2_0_0::{Size, Tag};
+use std::str::FromStr;
+
+use crate::v2_0_0::{Size, Tag, Tier};
 
 use super::PlayerTarget;
 
@@ -8,10 +10,24 @@ pub enum TargetCondition {
     Never,
     IsSelf,
     Adjacent,
+    /// The item directly to the left of the source
+    AdjacentLeft,
+    /// The item directly to the right of the source
+    AdjacentRight,
+    /// Any item to the left of the source
+    LeftOf,
+    /// Any item to the right of the source
+    RightOf,
+    /// The leftmost item among those matching the rest of the condition
+    Leftmost,
+    /// The rightmost item among those matching the rest of the condition
+    Rightmost,
     HasCooldown,
     HasOwner(PlayerTarget),
     HasTag(Tag),
     HasSize(Size),
+    /// Items that start at the given tier, legendary items start at `Tier::Legendary`
+    HasStartingTier(Tier),
     NameIncludes(String),
 
     And(Box<TargetCondition>, Box<TargetCondition>),
@@ -60,6 +76,12 @@ impl std::fmt::Display for TargetCondition {
                 write!(f, "TargetCondition::Raw({i:?}.to_string())")
             }
             TargetCondition::Adjacent => write!(f, "TargetCondition::Adjacent"),
+            TargetCondition::AdjacentLeft => write!(f, "TargetCondition::AdjacentLeft"),
+            TargetCondition::AdjacentRight => write!(f, "TargetCondition::AdjacentRight"),
+            TargetCondition::LeftOf => write!(f, "TargetCondition::LeftOf"),
+            TargetCondition::RightOf => write!(f, "TargetCondition::RightOf"),
+            TargetCondition::Leftmost => write!(f, "TargetCondition::Leftmost"),
+            TargetCondition::Rightmost => write!(f, "TargetCondition::Rightmost"),
             TargetCondition::HasCooldown => write!(f, "TargetCondition::HasCooldown"),
             TargetCondition::Always => write!(f, "TargetCondition::Always"),
             TargetCondition::Never => write!(f, "TargetCondition::Never"),
@@ -68,7 +90,10 @@ impl std::fmt::Display for TargetCondition {
                 write!(f, "TargetCondition::HasOwner(PlayerTarget::{i:?})")
             }
             TargetCondition::HasSize(i) => write!(f, "TargetCondition::HasSize(Size::{i:?})"),
-            TargetCondition::Not(i) => write!(f, "TargetCondition::Not({i})"),
+            TargetCondition::HasStartingTier(i) => {
+                write!(f, "TargetCondition::HasStartingTier(Tier::{i:?})")
+            }
+            TargetCondition::Not(i) => write!(f, "TargetCondition::Not(Box::new({i}))"),
             TargetCondition::NameIncludes(i) => {
                 write!(f, "TargetCondition::NameIncludes({i:?}.to_string())")
             }
@@ -86,4 +111,139 @@ impl TargetCondition {
             s => TargetCondition::Raw(s.to_string()),
         }
     }
+
+    /// Parses a list of tags such as `"tool or food"` or `"potion, reagent or food"` into a
+    /// condition matching any of them.
+    pub fn from_tags(s: &str) -> Option<Self> {
+        s.split(" or ")
+            .flat_map(|s| s.split(','))
+            .map(|s| s.trim())
+            .filter(|s| !s.is_empty())
+            .map(|s| {
+                Tag::from_str(s)
+                    .or_else(|e| s.strip_suffix('s').map(Tag::from_str).unwrap_or(Err(e)))
+                    .map(TargetCondition::HasTag)
+                    .ok()
+            })
+            .reduce(|a, b| match (a, b) {
+                (Some(a), Some(b)) => Some(a | b),
+                _ => None,
+            })
+            .flatten()
+    }
+
+    /// Parses positional phrases such as `"the weapon to the left of this"`,
+    /// `"items to the right of this"` or `"your leftmost shield item"`.
+    pub fn from_position(s: &str) -> Option<Self> {
+        let s = s.trim();
+        let (position, kind) = if let Some(kind) = s.strip_prefix("your leftmost ") {
+            (
+                TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Leftmost,
+                kind,
+            )
+        } else if let Some(kind) = s.strip_prefix("your rightmost ") {
+            (
+                TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Rightmost,
+                kind,
+            )
+        } else {
+            let (kind, left) = if let Some(kind) = s
+                .strip_suffix(" to the left of this")
+                .or_else(|| s.strip_suffix(" to the left"))
+            {
+                (kind, true)
+            } else if let Some(kind) = s
+                .strip_suffix(" to the right of this")
+                .or_else(|| s.strip_suffix(" to the right"))
+            {
+                (kind, false)
+            } else {
+                return None;
+            };
+            match (kind.strip_prefix("the "), left) {
+                (Some(kind), true) => (TargetCondition::AdjacentLeft, kind),
+                (Some(kind), false) => (TargetCondition::AdjacentRight, kind),
+                (None, true) => (TargetCondition::LeftOf, kind),
+                (None, false) => (TargetCondition::RightOf, kind),
+            }
+        };
+        match TargetCondition::from_item_kind(kind)? {
+            TargetCondition::Always => Some(position),
+            kind => Some(position & kind),
+        }
+    }
+
+    /// Parses what a "for each" phrase counts, such as `"adjacent food"`,
+    /// `"other aquatic item you have"`, `"weapon or friend your enemy has"` or
+    /// `"item to the left of this"`.
+    pub fn from_for_each(s: &str) -> Option<Self> {
+        let s = s.trim();
+        if let Some(kind) = s.strip_prefix("adjacent ") {
+            return match TargetCondition::from_item_kind(kind)? {
+                TargetCondition::Always => Some(TargetCondition::Adjacent),
+                kind => Some(TargetCondition::Adjacent & kind),
+            };
+        }
+        if let Some(condition) = TargetCondition::from_position(s) {
+            return Some(condition);
+        }
+        let (owner, kind) = if let Some(kind) = s.strip_suffix(" you have") {
+            match kind.strip_prefix("other ") {
+                Some(kind) => (
+                    TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf,
+                    kind,
+                ),
+                None => (TargetCondition::HasOwner(PlayerTarget::Player), kind),
+            }
+        } else if let Some(kind) = s.strip_suffix(" your enemy has") {
+            (TargetCondition::HasOwner(PlayerTarget::Opponent), kind)
+        } else {
+            return None;
+        };
+        match TargetCondition::from_item_kind(kind)? {
+            TargetCondition::Always => Some(owner),
+            kind => Some(owner & kind),
+        }
+    }
+
+    /// Whether `condition` appears anywhere in this condition.
+    pub fn contains(&self, condition: &TargetCondition) -> bool {
+        match self {
+            TargetCondition::And(a, b) | TargetCondition::Or(a, b) => {
+                a.contains(condition) || b.contains(condition)
+            }
+            TargetCondition::Not(a) => a.contains(condition),
+            c => c == condition,
+        }
+    }
+
+    /// Parses the kind of item in phrases such as `"1 weapon"`, `"a large item"`,
+    /// `"1 non-weapon item"` or `"a small, non-legendary item"`. A bare `"item"` matches any item.
+    pub fn from_item_kind(s: &str) -> Option<Self> {
+        let s = s
+            .strip_suffix("item(s)")
+            .or_else(|| s.strip_suffix("items"))
+            .or_else(|| s.strip_suffix("item"))
+            .unwrap_or(s)
+            .trim();
+        if s.is_empty() {
+            Some(TargetCondition::Always)
+        } else if let Ok(size) = Size::try_from(s) {
+            Some(TargetCondition::HasSize(size))
+        } else if let Some((size, kind)) = s
+            .split_once(", ")
+            .and_then(|(size, kind)| Some((Size::try_from(size).ok()?, kind)))
+        {
+            match TargetCondition::from_item_kind(kind)? {
+                TargetCondition::Always => Some(TargetCondition::HasSize(size)),
+                kind => Some(TargetCondition::HasSize(size) & kind),
+            }
+        } else if s == "legendary" {
+            Some(TargetCondition::HasStartingTier(Tier::Legendary))
+        } else if let Some(s) = s.strip_prefix("non-") {
+            TargetCondition::from_item_kind(s).map(|c| !c)
+        } else {
+            TargetCondition::from_tags(s)
+        }
+    }
 }
@@ -1,9 +1,26 @@
+use std::str::FromStr;
+
 use crate::v2_0_0::{Size, Tag};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Condition {
     HasCardOfSize(Size),
     HasCardOfTag(Tag),
+    HasExactlyOneCardOfTag(Tag),
+    /// Both neighbours of the card holding the tooltip exist and carry the tag
+    AdjacentCardsHaveTag(Tag),
+}
+
+impl Condition {
+    /// Parses the item kind of "if you have a ..." into a size or tag condition.
+    pub fn has_card_of_kind(kind: &str) -> Option<Self> {
+        let kind = kind.strip_suffix(" item").unwrap_or(kind);
+        match (Size::try_from(kind), Tag::from_str(kind)) {
+            (Ok(size), _) => Some(Condition::HasCardOfSize(size)),
+            (_, Ok(tag)) => Some(Condition::HasCardOfTag(tag)),
+            _ => None,
+        }
+    }
 }
 
 impl std::fmt::Display for Condition {
@@ -15,6 +32,12 @@ impl std::fmt::Display for Condition {
             Condition::HasCardOfTag(i) => {
                 write!(f, "Condition::HasCardOfTag(Tag::{i:?})")
             }
+            Condition::HasExactlyOneCardOfTag(i) => {
+                write!(f, "Condition::HasExactlyOneCardOfTag(Tag::{i:?})")
+            }
+            Condition::AdjacentCardsHaveTag(i) => {
+                write!(f, "Condition::AdjacentCardsHaveTag(Tag::{i:?})")
+            }
         }
     }
 }
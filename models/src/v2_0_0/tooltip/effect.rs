@@ -6,6 +6,7 @@ use crate::v2_0_0::{Percentage, Tag, Tier};
 
 use super::{
     CardTarget, Condition, EffectValue, ObtainedEffectItem, PlayerTarget, TargetCondition,
+    TargetSelection,
 };
 
 static TODO: bool = true; // TODO: The primitive ones are duplicated
@@ -15,12 +16,35 @@ lazy_static::lazy_static! {}
 pub enum CardDerivedProperty {
     Value,
     Damage,
+    Count,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum PlayerDerivedProperty {
     MaximumHealth,
     CurrentHealth,
+    Shield,
+    Burn,
+    Poison,
+    Regen,
+    Gold,
+}
+
+impl TryFrom<&str> for PlayerDerivedProperty {
+    type Error = anyhow::Error;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "max health" => Ok(PlayerDerivedProperty::MaximumHealth),
+            "current health" | "health" => Ok(PlayerDerivedProperty::CurrentHealth),
+            "shield" => Ok(PlayerDerivedProperty::Shield),
+            "burn" => Ok(PlayerDerivedProperty::Burn),
+            "poison" => Ok(PlayerDerivedProperty::Poison),
+            "regen" => Ok(PlayerDerivedProperty::Regen),
+            "gold" => Ok(PlayerDerivedProperty::Gold),
+            property => anyhow::bail!("invalid player property {property}"),
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq)]
@@ -56,7 +80,8 @@ pub enum Effect {
     Freeze(CardTarget, f64),
     Slow(CardTarget, f64),
     Haste(CardTarget, f64),
-    Reload(CardTarget, u32),
+    /// Reloads that much ammo, or up to max ammo when `None`
+    Reload(CardTarget, Option<u32>),
     DamageImmunity(f64),
     Charge(CardTarget, f64),
     Destroy(CardTarget),
@@ -69,6 +94,8 @@ pub enum Effect {
     DealDamage(PlayerTarget, DerivedValue<u32>),
     UseCard(CardTarget),
     Upgrade(Tier, CardTarget),
+    /// Turns the card holding the effect into a copy of the targeted card at the given tier
+    TransformIntoCopy(CardTarget, Tier),
     PermanentMaxHealthIncrease(DerivedValue<u32>),
     IncreaseMaxAmmo(CardTarget, EffectValue<u32>),
     ObtainItem(Vec<ObtainedEffectItem>),
@@ -95,7 +122,7 @@ impl std::fmt::Display for Effect {
             Effect::Poison(i, j) => write!(f, "Effect::Poison({i}, {j})"),
             Effect::Heal(i, j) => write!(f, "Effect::Heal({i}, {j})"),
             Effect::IncreaseDamage(i, j) => write!(f, "Effect::IncreaseDamage({i}, {j})"),
-            Effect::Reload(i, j) => write!(f, "Effect::Reload({i}, {j})"),
+            Effect::Reload(i, j) => write!(f, "Effect::Reload({i}, {j:?})"),
             Effect::Haste(i, j) => write!(f, "Effect::Haste({i}, {j:.2})"),
             Effect::Charge(i, j) => write!(f, "Effect::Charge({i}, {j:.2})"),
             Effect::GainGold(i, j) => write!(f, "Effect::GainGold({i}, {j})"),
@@ -117,6 +144,9 @@ impl std::fmt::Display for Effect {
             }
             Effect::IncreaseMaxAmmo(i, j) => write!(f, "Effect::IncreaseMaxAmmo({i}, {j})"),
             Effect::Upgrade(i, j) => write!(f, "Effect::Upgrade(Tier::{i:?}, {j})"),
+            Effect::TransformIntoCopy(i, j) => {
+                write!(f, "Effect::TransformIntoCopy({i}, Tier::{j:?})")
+            }
             Effect::GainXp(i, j) => write!(f, "Effect::GainXp({i}, {j})"),
             Effect::MultiEffect(effects) => {
                 let effect_str = effects
@@ -138,7 +168,29 @@ impl Effect {
         let tooltip = tooltip.trim();
 
         if tooltip == "use this." {
-            return Effect::Use(CardTarget(1, TargetCondition::IsSelf));
+            return Effect::Use(CardTarget(
+                1,
+                TargetCondition::IsSelf,
+                TargetSelection::Random,
+            ));
+        }
+
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_USE_ITEM.captures(tooltip) {
+            if let (Some(count_str), Some(kind_str)) = (captures.get(1), captures.get(2)) {
+                let count = match count_str.as_str() {
+                    "a" | "an" => Ok(1),
+                    count_str => count_str.parse::<usize>(),
+                };
+                let owner = TargetCondition::HasOwner(PlayerTarget::Player);
+                let condition = match TargetCondition::from_item_kind(kind_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(owner),
+                    Some(condition) => Some(owner & condition),
+                    None => None,
+                };
+                if let (Ok(count), Some(condition)) = (count, condition) {
+                    return Effect::UseCard(CardTarget(count, condition, TargetSelection::Random));
+                }
+            }
         }
 
         if let Some(captures) = crate::v2_0_0::re::EFFECT_REDUCE_CD_FLAT.captures(tooltip) {
@@ -147,14 +199,118 @@ impl Effect {
                     Ok(c) => c,
                     Err(_) => return Effect::Raw(tooltip.to_string()),
                 };
-                let todo = (); // TODO: this is sus
                 return Effect::CooldownReduction(
-                    CardTarget(1, TargetCondition::IsSelf),
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                     EffectValue::Flat(amount as f64),
                 );
             }
         }
 
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_INCREASE_CD_FLAT.captures(tooltip) {
+            if let Some(amount_str) = captures.get(1) {
+                let amount = match amount_str.as_str().parse::<u32>() {
+                    Ok(c) => c,
+                    Err(_) => return Effect::Raw(tooltip.to_string()),
+                };
+                return Effect::CooldownReduction(
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+                    EffectValue::Flat(-(amount as f64)),
+                );
+            }
+        }
+
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_REDUCE_CD_PERCENTAGE.captures(tooltip) {
+            if let Some(amount_str) = captures.get(1) {
+                let amount = match amount_str.as_str().parse::<u32>() {
+                    Ok(c) => c,
+                    Err(_) => return Effect::Raw(tooltip.to_string()),
+                };
+                return Effect::CooldownReduction(
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+                    EffectValue::Percentage(amount as f64),
+                );
+            }
+        }
+
+        if let Some(captures) =
+            crate::v2_0_0::re::EFFECT_REDUCE_OTHER_CD_PERCENTAGE.captures(tooltip)
+        {
+            if let Some(amount_str) = captures.get(1) {
+                let amount = match amount_str.as_str().parse::<u32>() {
+                    Ok(c) => c,
+                    Err(_) => return Effect::Raw(tooltip.to_string()),
+                };
+                return Effect::CooldownReduction(
+                    CardTarget(
+                        usize::MAX,
+                        TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf,
+                        TargetSelection::Random,
+                    ),
+                    EffectValue::Percentage(amount as f64),
+                );
+            }
+        }
+
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_EQUAL_TO_PLAYER_PROPERTY.captures(tooltip)
+        {
+            if let (Some(effect_str), Some(player_str), Some(property_str)) =
+                (captures.get(1), captures.get(3), captures.get(4))
+            {
+                let fraction = match captures.get(2).map(|p| p.as_str().parse::<f64>()) {
+                    Some(Ok(p)) => Percentage::from_percentage_value(p).as_fraction() as f32,
+                    Some(Err(_)) => return Effect::Raw(tooltip.to_string()),
+                    None => 1.0,
+                };
+                let player = match player_str.as_str() {
+                    "your" => PlayerTarget::Player,
+                    _ => PlayerTarget::Opponent,
+                };
+                if let Ok(property) = PlayerDerivedProperty::try_from(property_str.as_str()) {
+                    let value = DerivedValue::FromPlayer(
+                        CardTarget(
+                            1,
+                            TargetCondition::HasOwner(player),
+                            TargetSelection::Random,
+                        ),
+                        property,
+                        fraction,
+                    );
+                    return match effect_str.as_str() {
+                        "deal damage" => Effect::DealDamage(PlayerTarget::Opponent, value),
+                        "heal" => Effect::Heal(PlayerTarget::Player, value),
+                        "shield" => Effect::Shield(PlayerTarget::Player, value),
+                        "burn" => Effect::Burn(PlayerTarget::Opponent, value),
+                        "poison" => Effect::Poison(PlayerTarget::Opponent, value),
+                        _ => Effect::Regen(PlayerTarget::Player, value),
+                    };
+                }
+            }
+        }
+
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_FOR_EACH.captures(tooltip) {
+            if let (Some(effect_str), Some(amount_str), Some(counted_str)) =
+                (captures.get(1), captures.get(2), captures.get(3))
+            {
+                if let (Ok(amount), Some(condition)) = (
+                    amount_str.as_str().parse::<f32>(),
+                    TargetCondition::from_for_each(counted_str.as_str()),
+                ) {
+                    let value = DerivedValue::FromCard(
+                        CardTarget(usize::MAX, condition, TargetSelection::Random),
+                        CardDerivedProperty::Count,
+                        amount,
+                    );
+                    return match effect_str.as_str() {
+                        "deal" => Effect::DealDamage(PlayerTarget::Opponent, value),
+                        "burn" => Effect::Burn(PlayerTarget::Opponent, value),
+                        "poison" => Effect::Poison(PlayerTarget::Opponent, value),
+                        "shield" => Effect::Shield(PlayerTarget::Player, value),
+                        _ => Effect::Heal(PlayerTarget::Player, value),
+                    };
+                }
+            }
+        }
+
         if let Some(captures) = crate::v2_0_0::re::EFFECT_DEAL_DAMAGE.captures(tooltip) {
             if let Some(amount_str) = captures.get(1) {
                 let amount = match amount_str.as_str().parse::<u32>() {
@@ -301,7 +457,7 @@ impl Effect {
                     return Effect::Poison(
                         PlayerTarget::Opponent,
                         DerivedValue::FromCard(
-                            CardTarget(1, TargetCondition::IsSelf),
+                            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                             CardDerivedProperty::Damage,
                             poison_pct.as_fraction() as f32,
                         ),
@@ -320,7 +476,7 @@ impl Effect {
                     return Effect::Shield(
                         PlayerTarget::Player,
                         DerivedValue::FromCard(
-                            CardTarget(1, TargetCondition::IsSelf),
+                            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                             CardDerivedProperty::Damage,
                             shield_pct.as_fraction() as f32,
                         ),
@@ -333,7 +489,7 @@ impl Effect {
             return Effect::Shield(
                 PlayerTarget::Player,
                 DerivedValue::FromCard(
-                    CardTarget(1, TargetCondition::IsSelf),
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                     CardDerivedProperty::Damage,
                     1.0,
                 ),
@@ -350,7 +506,7 @@ impl Effect {
                     return Effect::Heal(
                         PlayerTarget::Player,
                         DerivedValue::FromCard(
-                            CardTarget(1, TargetCondition::IsSelf),
+                            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                             CardDerivedProperty::Damage,
                             heal_pct.as_fraction() as f32,
                         ),
@@ -363,7 +519,7 @@ impl Effect {
             return Effect::Heal(
                 PlayerTarget::Player,
                 DerivedValue::FromCard(
-                    CardTarget(1, TargetCondition::IsSelf),
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                     CardDerivedProperty::Damage,
                     1.0,
                 ),
@@ -380,7 +536,7 @@ impl Effect {
                     return Effect::Burn(
                         PlayerTarget::Opponent,
                         DerivedValue::FromCard(
-                            CardTarget(1, TargetCondition::IsSelf),
+                            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                             CardDerivedProperty::Damage,
                             burn_pct.as_fraction() as f32,
                         ),
@@ -400,23 +556,374 @@ impl Effect {
             if let Some(amount_str) = captures.get(1) {
                 if let Ok(amount) = amount_str.as_str().parse::<u32>() {
                     return Effect::IncreaseMaxAmmo(
-                        CardTarget(1, TargetCondition::IsSelf),
+                        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                         EffectValue::Flat(amount),
                     );
                 }
             }
         }
+        if tooltip == "reload this." {
+            return Effect::Reload(
+                CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+                None,
+            );
+        }
+        if let Some(capture) = crate::v2_0_0::re::TIMED_EFFECT_POSITIONAL.captures(tooltip) {
+            if let (Some(kind_str), Some(target_str), Some(seconds_str)) =
+                (capture.get(1), capture.get(2), capture.get(3))
+            {
+                if let (Some(condition), Ok(seconds)) = (
+                    TargetCondition::from_position(target_str.as_str()),
+                    seconds_str.as_str().parse::<f64>(),
+                ) {
+                    let count = match condition.contains(&TargetCondition::LeftOf)
+                        || condition.contains(&TargetCondition::RightOf)
+                    {
+                        true => usize::MAX,
+                        false => 1,
+                    };
+                    let target = CardTarget(count, condition, TargetSelection::Random);
+                    return match kind_str.as_str() {
+                        "haste" => Effect::Haste(target, seconds),
+                        "slow" => Effect::Slow(target, seconds),
+                        "charge" => Effect::Charge(target, seconds),
+                        _ => Effect::Freeze(target, seconds),
+                    };
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::TIMED_EFFECT_ORDERED.captures(tooltip) {
+            if let (Some(kind_str), Some(order_str), Some(item_str), Some(seconds_str)) = (
+                capture.get(1),
+                capture.get(2),
+                capture.get(4),
+                capture.get(5),
+            ) {
+                let owner = match (capture.get(3), kind_str.as_str()) {
+                    (Some(..), _) | (None, "slow" | "freeze") => PlayerTarget::Opponent,
+                    _ => PlayerTarget::Player,
+                };
+                let condition = match TargetCondition::from_item_kind(item_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(TargetCondition::HasOwner(owner)),
+                    Some(condition) => Some(TargetCondition::HasOwner(owner) & condition),
+                    None => None,
+                };
+                if let (Some(condition), Some(selection), Ok(seconds)) = (
+                    condition,
+                    TargetSelection::from_superlative(order_str.as_str()),
+                    seconds_str.as_str().parse::<f64>(),
+                ) {
+                    let target = CardTarget(1, condition, selection);
+                    return match kind_str.as_str() {
+                        "haste" => Effect::Haste(target, seconds),
+                        "slow" => Effect::Slow(target, seconds),
+                        "charge" => Effect::Charge(target, seconds),
+                        _ => Effect::Freeze(target, seconds),
+                    };
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::HASTE_N_FOR_M.captures(tooltip) {
+            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
+                if let (Ok(n), Ok(m)) = (
+                    n_str.as_str().parse::<usize>(),
+                    m_str.as_str().parse::<f64>(),
+                ) {
+                    return Effect::Haste(
+                        CardTarget(
+                            n,
+                            TargetCondition::HasOwner(PlayerTarget::Player),
+                            TargetSelection::Random,
+                        ),
+                        m,
+                    );
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::SLOW_N_FOR_M.captures(tooltip) {
+            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
+                if let (Ok(n), Ok(m)) = (
+                    n_str.as_str().parse::<usize>(),
+                    m_str.as_str().parse::<f64>(),
+                ) {
+                    return Effect::Slow(
+                        CardTarget(
+                            n,
+                            TargetCondition::HasOwner(PlayerTarget::Opponent),
+                            TargetSelection::Random,
+                        ),
+                        m,
+                    );
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::FREEZE_N_FOR_M.captures(tooltip) {
+            if let (Some(n_str), Some(m_str)) = (capture.get(1), capture.get(2)) {
+                if let (Ok(n), Ok(m)) = (
+                    n_str.as_str().parse::<usize>(),
+                    m_str.as_str().parse::<f64>(),
+                ) {
+                    return Effect::Freeze(
+                        CardTarget(
+                            n,
+                            TargetCondition::HasOwner(PlayerTarget::Opponent),
+                            TargetSelection::Random,
+                        ),
+                        m,
+                    );
+                }
+            }
+        }
+        if let Some(capture) = crate::v2_0_0::re::FREEZE_N_FOR_M_OF_SIZE.captures(tooltip) {
+            if let (Some(n_str), Some(size_str), Some(m_str)) =
+                (capture.get(1), capture.get(2), capture.get(3))
+            {
+                if let (Ok(n), Ok(m), Ok(size)) = (
+                    n_str.as_str().parse::<usize>(),
+                    m_str.as_str().parse::<f64>(),
+                    size_str.as_str().try_into(),
+                ) {
+                    return Effect::Freeze(
+                        CardTarget(
+                            n,
+                            TargetCondition::HasOwner(PlayerTarget::Opponent)
+                                & TargetCondition::HasSize(size)
+                                & TargetCondition::HasCooldown,
+                            TargetSelection::Random,
+                        ),
+                        m,
+                    );
+                }
+            }
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_GAIN_STAT_FOR_THE_FIGHT.captures(tooltip)
+        {
+            if let (Some(scope_str), Some(kind_str), Some(amount_str), Some(stat_str)) = (
+                captures.get(1),
+                captures.get(2),
+                captures.get(3),
+                captures.get(4),
+            ) {
+                let owner = TargetCondition::HasOwner(PlayerTarget::Player);
+                let target = match (scope_str.as_str(), kind_str.as_str()) {
+                    ("this", "") => Some(CardTarget(
+                        1,
+                        TargetCondition::IsSelf,
+                        TargetSelection::Random,
+                    )),
+                    ("this", _) => None,
+                    (scope, kind) => TargetCondition::from_item_kind(kind).map(|kind| {
+                        let scope = match scope {
+                            "adjacent" => TargetCondition::Adjacent,
+                            "your other" => owner & !TargetCondition::IsSelf,
+                            _ => owner,
+                        };
+                        match kind {
+                            TargetCondition::Always => {
+                                CardTarget(usize::MAX, scope, TargetSelection::Random)
+                            }
+                            kind => CardTarget(usize::MAX, scope & kind, TargetSelection::Random),
+                        }
+                    }),
+                };
+                if let (Some(target), Ok(amount)) = (target, amount_str.as_str().parse::<u32>()) {
+                    return match stat_str.as_str() {
+                        "damage" => Effect::IncreaseDamage(target, EffectValue::Flat(amount)),
+                        _ => Effect::GainShield(target, EffectValue::Flat(amount as f64)),
+                    };
+                }
+            }
+        }
+        if let Some(captures) =
+            crate::v2_0_0::re::EFFECT_GAIN_SHIELD_AND_DAMAGE_FOR_THE_FIGHT.captures(tooltip)
+        {
+            if let (Some(shield_str), Some(damage_str)) = (captures.get(1), captures.get(2)) {
+                if let (Ok(shield), Ok(damage)) = (
+                    shield_str.as_str().parse::<f64>(),
+                    damage_str.as_str().parse::<u32>(),
+                ) {
+                    let owner = TargetCondition::HasOwner(PlayerTarget::Player);
+                    return Effect::MultiEffect(vec![
+                        Effect::GainShield(
+                            CardTarget(
+                                usize::MAX,
+                                owner.clone() & TargetCondition::HasTag(Tag::Shield),
+                                TargetSelection::Random,
+                            ),
+                            EffectValue::Flat(shield),
+                        ),
+                        Effect::IncreaseDamage(
+                            CardTarget(
+                                usize::MAX,
+                                owner & TargetCondition::HasTag(Tag::Weapon),
+                                TargetSelection::Random,
+                            ),
+                            EffectValue::Flat(damage),
+                        ),
+                    ]);
+                }
+            }
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_CHARGE_THIS.captures(tooltip) {
+            if let Some(seconds_str) = captures.get(1) {
+                if let Ok(seconds) = seconds_str.as_str().parse::<f64>() {
+                    return Effect::Charge(
+                        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+                        seconds,
+                    );
+                }
+            }
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_CHARGE_ADJACENT.captures(tooltip) {
+            if let Some(seconds_str) = captures.get(1) {
+                if let Ok(seconds) = seconds_str.as_str().parse::<f64>() {
+                    return Effect::Charge(
+                        CardTarget(
+                            usize::MAX,
+                            TargetCondition::Adjacent,
+                            TargetSelection::Random,
+                        ),
+                        seconds,
+                    );
+                }
+            }
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_CHARGE_N_ITEMS.captures(tooltip) {
+            if let (Some(count_str), Some(kind_str), Some(seconds_str)) =
+                (captures.get(1), captures.get(2), captures.get(3))
+            {
+                let count = match count_str.as_str() {
+                    "a" | "an" => Ok(1),
+                    count_str => count_str.parse::<usize>(),
+                };
+                let owner = TargetCondition::HasOwner(PlayerTarget::Player);
+                let condition = match TargetCondition::from_item_kind(kind_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(owner),
+                    Some(condition) => Some(owner & condition),
+                    None => None,
+                };
+                if let (Ok(count), Some(condition), Ok(seconds)) =
+                    (count, condition, seconds_str.as_str().parse::<f64>())
+                {
+                    return Effect::Charge(
+                        CardTarget(count, condition, TargetSelection::Random),
+                        seconds,
+                    );
+                }
+            }
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_RELOAD_THIS_N_AMMO.captures(tooltip) {
+            if let Some(amount_str) = captures.get(1) {
+                if let Ok(amount) = amount_str.as_str().parse::<u32>() {
+                    return Effect::Reload(
+                        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+                        Some(amount),
+                    );
+                }
+            }
+        }
+        if tooltip == "reload adjacent items." {
+            return Effect::Reload(
+                CardTarget(
+                    usize::MAX,
+                    TargetCondition::Adjacent,
+                    TargetSelection::Random,
+                ),
+                None,
+            );
+        }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_RELOAD_N_ITEMS.captures(tooltip) {
+            if let Some(count_str) = captures.get(1) {
+                let count = match count_str.as_str() {
+                    "an" => Ok(1),
+                    count_str => count_str.parse::<usize>(),
+                };
+                if let Ok(count) = count {
+                    return Effect::Reload(
+                        CardTarget(
+                            count,
+                            TargetCondition::HasOwner(PlayerTarget::Player),
+                            TargetSelection::Random,
+                        ),
+                        None,
+                    );
+                }
+            }
+        }
+        if let Some(captures) =
+            crate::v2_0_0::re::EFFECT_RELOAD_N_AMMO_ITEMS_M_AMMO.captures(tooltip)
+        {
+            if let (Some(count_str), Some(amount_str)) = (captures.get(1), captures.get(2)) {
+                if let (Ok(count), Ok(amount)) = (
+                    count_str.as_str().parse::<usize>(),
+                    amount_str.as_str().parse::<u32>(),
+                ) {
+                    return Effect::Reload(
+                        CardTarget(
+                            count,
+                            TargetCondition::HasOwner(PlayerTarget::Player)
+                                & TargetCondition::HasTag(Tag::Ammo),
+                            TargetSelection::Random,
+                        ),
+                        Some(amount),
+                    );
+                }
+            }
+        }
+        if let Some(captures) =
+            crate::v2_0_0::re::EFFECT_RELOAD_ADJACENT_AMMO_ITEMS_M_AMMO.captures(tooltip)
+        {
+            if let Some(amount_str) = captures.get(1) {
+                if let Ok(amount) = amount_str.as_str().parse::<u32>() {
+                    return Effect::Reload(
+                        CardTarget(
+                            usize::MAX,
+                            TargetCondition::Adjacent & TargetCondition::HasTag(Tag::Ammo),
+                            TargetSelection::Random,
+                        ),
+                        Some(amount),
+                    );
+                }
+            }
+        }
         if crate::v2_0_0::re::EFFECT_UPGRADE_RANDOM_PIGGLE.is_match(tooltip) {
             return Effect::Upgrade(
                 Tier::Bronze,
-                CardTarget(1, TargetCondition::NameIncludes("piggle".to_string())),
+                CardTarget(
+                    1,
+                    TargetCondition::NameIncludes("piggle".to_string()),
+                    TargetSelection::Random,
+                ),
             );
         }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_TRANSFORM_INTO_COPY.captures(tooltip) {
+            if let (Some(tier_str), Some(target_str)) = (captures.get(1), captures.get(2)) {
+                let condition = match target_str
+                    .as_str()
+                    .strip_prefix("another ")
+                    .and_then(|s| s.strip_suffix(" you have"))
+                {
+                    Some(kind) => TargetCondition::from_item_kind(kind).map(|kind| {
+                        TargetCondition::HasOwner(PlayerTarget::Player)
+                            & !TargetCondition::IsSelf
+                            & kind
+                    }),
+                    None => TargetCondition::from_position(target_str.as_str()),
+                };
+                if let (Ok(tier), Some(condition)) = (Tier::try_from(tier_str.as_str()), condition)
+                {
+                    return Effect::TransformIntoCopy(
+                        CardTarget(1, condition, TargetSelection::Random),
+                        tier,
+                    );
+                }
+            }
+        }
         if tooltip == "deal damage equal to double this item's value." {
             return Effect::DealDamage(
                 PlayerTarget::Opponent,
                 DerivedValue::FromCard(
-                    CardTarget(1, TargetCondition::IsSelf),
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                     CardDerivedProperty::Value,
                     2.0,
                 ),
@@ -426,18 +933,36 @@ impl Effect {
             return Effect::DealDamage(
                 PlayerTarget::Opponent,
                 DerivedValue::FromCard(
-                    CardTarget(1, TargetCondition::IsSelf),
+                    CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
                     CardDerivedProperty::Value,
                     1.0,
                 ),
             );
         }
-        if tooltip == "destroy an item for the fight." {
+        if tooltip == "destroy this." {
             return Effect::Destroy(CardTarget(
                 1,
-                TargetCondition::HasOwner(PlayerTarget::Opponent),
+                TargetCondition::IsSelf,
+                TargetSelection::Random,
             ));
         }
+        if let Some(captures) = crate::v2_0_0::re::EFFECT_DESTROY_N_ITEMS.captures(tooltip) {
+            if let (Some(count_str), Some(kind_str)) = (captures.get(1), captures.get(2)) {
+                let count = match count_str.as_str() {
+                    "a" | "an" => Ok(1),
+                    count_str => count_str.parse::<usize>(),
+                };
+                let enemy = TargetCondition::HasOwner(PlayerTarget::Opponent);
+                let condition = match TargetCondition::from_item_kind(kind_str.as_str()) {
+                    Some(TargetCondition::Always) => Some(enemy),
+                    Some(condition) => Some(enemy & condition),
+                    None => None,
+                };
+                if let (Ok(count), Some(condition)) = (count, condition) {
+                    return Effect::Destroy(CardTarget(count, condition, TargetSelection::Random));
+                }
+            }
+        }
         if tooltip == "gain 1 xp. if you had wanted poster in play, gain 1 additional xp." {
             return Effect::MultiEffect(vec![
                 Effect::GainXp(PlayerTarget::Player, 1),
@@ -445,6 +970,7 @@ impl Effect {
                     CardTarget(
                         1,
                         TargetCondition::NameIncludes("wanted poster".to_string()),
+                        TargetSelection::Random,
                     ),
                     Effect::GainXp(PlayerTarget::Player, 1).into(),
                 ),
@@ -1,4 +1,7 @@
-use super::{CardTarget, DerivedValue, Effect, GlobalEvent, PlayerTarget, TargetCondition};
+use super::{
+    Action, CardTarget, DerivedValue, Effect, GlobalEvent, PlayerTarget, TargetCondition,
+    TargetSelection,
+};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum EffectEvent {
@@ -10,6 +13,8 @@ pub enum EffectEvent {
     OnCardSold(Effect),
     OnCardUsed(TargetCondition, Effect),
     OnCrit(TargetCondition, Effect),
+    /// Fires whenever the owner performs any of the actions
+    OnAction(Vec<Action>, Effect),
     OnFirstTime(GlobalEvent, Effect),
     Raw(String),
 }
@@ -25,6 +30,14 @@ impl std::fmt::Display for EffectEvent {
             EffectEvent::OnFightStart(i) => write!(f, "EffectEvent::OnFightStart({i})"),
             EffectEvent::OnCardUsed(i, j) => write!(f, "EffectEvent::OnCardUsed({i}, {j})"),
             EffectEvent::OnCrit(i, j) => write!(f, "EffectEvent::OnCrit({i}, {j})"),
+            EffectEvent::OnAction(i, j) => write!(
+                f,
+                "EffectEvent::OnAction(vec![{}], {j})",
+                i.iter()
+                    .map(|a| a.to_string())
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            ),
             EffectEvent::OnFirstTime(i, j) => write!(f, "EffectEvent::OnFirstTime({i}, {j})"),
             EffectEvent::Raw(i) => write!(f, "EffectEvent::Raw({i:?}.to_string())"),
         }
@@ -99,6 +112,7 @@ impl EffectEvent {
             return EffectEvent::OnCooldown(Effect::Use(CardTarget(
                 usize::MAX,
                 !TargetCondition::IsSelf & TargetCondition::HasOwner(PlayerTarget::Player),
+                TargetSelection::Random,
             )));
         }
 
@@ -12,7 +12,7 @@ where
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             EffectValue::Flat(i) => write!(f, "EffectValue::Flat({i:.2})"),
-            EffectValue::Percentage(i) => write!(f, "EffectValue::Percentage({i})"),
+            EffectValue::Percentage(i) => write!(f, "EffectValue::Percentage({i:.2})"),
         }
     }
 }
@@ -1,6 +1,7 @@
 #[derive(Debug, Clone, PartialEq)]
 pub enum GlobalEvent {
     PlayerFallsBelowHpPercentage(f64),
+    EnemyFallsBelowHpPercentage(f64),
 }
 
 impl std::fmt::Display for GlobalEvent {
@@ -9,6 +10,9 @@ impl std::fmt::Display for GlobalEvent {
             GlobalEvent::PlayerFallsBelowHpPercentage(i) => {
                 write!(f, "GlobalEvent::PlayerFallsBelowHpPercentage({i:.2})")
             }
+            GlobalEvent::EnemyFallsBelowHpPercentage(i) => {
+                write!(f, "GlobalEvent::EnemyFallsBelowHpPercentage({i:.2})")
+            }
         }
     }
 }
@@ -1,3 +1,4 @@
+pub mod action;
 pub mod base;
 pub mod card_target;
 pub mod card_target_condition;
@@ -10,6 +11,7 @@ pub mod modifier;
 pub mod obtained_item;
 pub mod player_target;
 
+pub use action::*;
 pub use base::*;
 pub use card_target::*;
 pub use card_target_condition::*;
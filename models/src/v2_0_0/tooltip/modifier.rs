@@ -1,29 +1,44 @@
 use crate::v2_0_0::Percentage;
 
-use super::EffectValue;
+use super::{DerivedValue, EffectValue, TargetCondition};
 
 #[derive(Debug, Clone, PartialEq)]
 pub enum Modifier {
     DoubleCritDamage,
+    QuadrupleCritDamage,
     WeaponDamage(EffectValue<u32>),
     LessDamageTaken(EffectValue<u32>),
     CritChance(Percentage),
+    GrantCritChance(TargetCondition, Percentage),
+    GrantCooldownReduction(TargetCondition, Percentage),
     Cooldown(f64),
     Ammo(u32),
     Multicast(u32),
+    IncreasedMulticast(DerivedValue<u32>),
     IncreasedValue(u32),
+    /// Regen the owner has for the whole fight
+    Regen(DerivedValue<u32>),
     Radiant,
+    Lifesteal,
 }
 
 impl std::fmt::Display for Modifier {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Modifier::DoubleCritDamage => write!(f, "Modifier::DoubleCritDamage"),
+            Modifier::QuadrupleCritDamage => write!(f, "Modifier::QuadrupleCritDamage"),
             Modifier::Radiant => write!(f, "Modifier::Radiant"),
+            Modifier::Lifesteal => write!(f, "Modifier::Lifesteal"),
             Modifier::Cooldown(i) => write!(f, "Modifier::Cooldown({i:.2})"),
             Modifier::Ammo(i) => write!(f, "Modifier::Ammo({i})"),
             Modifier::Multicast(i) => write!(f, "Modifier::Multicast({i})"),
+            Modifier::IncreasedMulticast(i) => write!(f, "Modifier::IncreasedMulticast({i})"),
+            Modifier::Regen(i) => write!(f, "Modifier::Regen({i})"),
             Modifier::CritChance(i) => write!(f, "Modifier::CritChance({i:.2})"),
+            Modifier::GrantCritChance(i, j) => write!(f, "Modifier::GrantCritChance({i}, {j:.2})"),
+            Modifier::GrantCooldownReduction(i, j) => {
+                write!(f, "Modifier::GrantCooldownReduction({i}, {j})")
+            }
             Modifier::LessDamageTaken(i) => write!(f, "Modifier::LessDamageTaken({i})"),
             Modifier::WeaponDamage(i) => write!(f, "Modifier::WeaponDamage({i})"),
             Modifier::IncreasedValue(i) => write!(f, "Modifier::IncreasedValue({i})"),
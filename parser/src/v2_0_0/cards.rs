@@ -2,7 +2,7 @@ use heck::ToPascalCase;
 use models::v2_0_0::Tooltip;
 use std::path::PathBuf;
 
-use super::{JsonValue, ModuleName, StructName, tag_strlist};
+use super::{tag_strlist, JsonValue, ModuleName, StructName};
 
 pub struct JsonCardFields {
     id: String,
@@ -338,6 +338,7 @@ impl CardSourceBuilder {
                 .await?;
 
                 let name_returned = name_owned.clone();
+                let id_returned = fields.id.clone();
                 let (module_name, struct_name, source_code) =
                     tokio::task::spawn_blocking(move || {
                         let StructName(struct_name) = StructName::card(&name_owned);
@@ -354,10 +355,11 @@ impl CardSourceBuilder {
                 let file_path = card_directory.join(format!("{}.rs", module_name));
                 tokio::fs::write(&file_path, source_code).await?;
 
-                Ok::<(String, String, String), anyhow::Error>((
+                Ok::<(String, String, String, String), anyhow::Error>((
                     module_name,
                     struct_name,
                     name_returned,
+                    id_returned,
                 ))
             }));
         }
@@ -369,18 +371,26 @@ impl CardSourceBuilder {
 
         // Generate mod.rs
         let mut mod_src = String::from("// @generated\n");
-        for (module_name, struct_name, _) in &struct_metadata_list {
+        for (module_name, struct_name, _, _) in &struct_metadata_list {
             mod_src.push_str(&format!(
                 "pub mod {module_name}; pub use {module_name}::{struct_name};\n",
             ));
         }
         mod_src.push_str("lazy_static::lazy_static!{\n    pub static ref CONSTRUCT_CARD_BY_NAME: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Card> = std::collections::HashMap::from([\n");
-        for (module_name, struct_name, name) in &struct_metadata_list {
+        for (module_name, struct_name, name, _) in &struct_metadata_list {
             mod_src.push_str(&format!(
                 r#"        ("{name}", {module_name}::{struct_name}::new as fn() -> _),"#
             ));
             mod_src.push_str("\n");
         }
+        mod_src.push_str("\n    ]);\n");
+        mod_src.push_str("    pub static ref CONSTRUCT_CARD_BY_ID: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Card> = std::collections::HashMap::from([\n");
+        for (module_name, struct_name, _, id) in &struct_metadata_list {
+            mod_src.push_str(&format!(
+                r#"        ("{id}", {module_name}::{struct_name}::new as fn() -> _),"#
+            ));
+            mod_src.push('\n');
+        }
         mod_src.push_str("\n    ]);\n}\n");
         tokio::fs::write(card_directory.join("mod.rs"), mod_src).await?;
         Ok(())
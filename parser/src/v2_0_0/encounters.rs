@@ -163,13 +163,18 @@ impl EncounterSourceBuilder {
             .as_array()
             .ok_or(anyhow::anyhow!("no data property on root object"))?;
 
+        let mut struct_metadata_list = vec![];
         for day_json in encounter_days_json {
             let day_module_name = day_json["day"]
                 .as_i64()
                 .map(|s| format!("day_{s}"))
                 .unwrap_or("day_event".to_string());
+            let day_variant = day_json["day"]
+                .as_i64()
+                .map(|i| format!("Numeric({i})"))
+                .unwrap_or("Event".to_string());
             encounters_mod_rs_source.push_str(&format!("pub mod {day_module_name};\n"));
-            let day_directory = &encounter_directory.join(day_module_name);
+            let day_directory = &encounter_directory.join(&day_module_name);
             std::fs::create_dir_all(day_directory)?;
             let mut day_mod_rs = String::from("// @generated\n");
             let groups = day_json["groups"]
@@ -186,6 +191,7 @@ impl EncounterSourceBuilder {
                     let StructName(struct_name) = StructName::encounter(name);
                     let ModuleName(module_name) = ModuleName::encounter(name);
                     let fields: JsonEncounterFields = json_encounter.try_into()?;
+                    let id = fields.id.clone();
                     let source = fields.to_source_code(&struct_name);
 
                     let syntax_tree = syn::parse_str(&source)?;
@@ -194,10 +200,44 @@ impl EncounterSourceBuilder {
                     day_mod_rs.push_str(&format!(
                         "pub mod {module_name};\npub use {module_name}::{struct_name};\n"
                     ));
+                    struct_metadata_list.push((
+                        format!("{day_module_name}::{module_name}::{struct_name}"),
+                        day_variant.clone(),
+                        name.to_string(),
+                        id,
+                    ));
                 }
             }
             std::fs::write(day_directory.join("mod.rs"), day_mod_rs)?;
         }
+
+        // Some encounters recur on several days under the same name; the
+        // name lookup keeps the earliest one, the id and day lookups are exact.
+        let mut seen_names = std::collections::HashSet::new();
+        encounters_mod_rs_source.push_str("lazy_static::lazy_static!{\n    pub static ref CONSTRUCT_ENCOUNTER_BY_NAME: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Encounter> = std::collections::HashMap::from([\n");
+        for (path, _, name, _) in &struct_metadata_list {
+            if !seen_names.insert(name) {
+                continue;
+            }
+            encounters_mod_rs_source
+                .push_str(&format!(r#"        ("{name}", {path}::new as fn() -> _),"#));
+            encounters_mod_rs_source.push('\n');
+        }
+        encounters_mod_rs_source.push_str("\n    ]);\n");
+        encounters_mod_rs_source.push_str("    pub static ref CONSTRUCT_ENCOUNTER_BY_ID: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Encounter> = std::collections::HashMap::from([\n");
+        for (path, _, _, id) in &struct_metadata_list {
+            encounters_mod_rs_source
+                .push_str(&format!(r#"        ("{id}", {path}::new as fn() -> _),"#));
+            encounters_mod_rs_source.push('\n');
+        }
+        encounters_mod_rs_source.push_str("\n    ]);\n");
+        encounters_mod_rs_source.push_str("    pub static ref ENCOUNTERS_BY_DAY: std::collections::HashMap<models::v2_0_0::EncounterDay, Vec<fn() -> models::v2_0_0::Encounter>> = {\n        let mut map: std::collections::HashMap<models::v2_0_0::EncounterDay, Vec<fn() -> models::v2_0_0::Encounter>> = std::collections::HashMap::new();\n");
+        for (path, day_variant, _, _) in &struct_metadata_list {
+            encounters_mod_rs_source.push_str(&format!(
+                "        map.entry(models::v2_0_0::EncounterDay::{day_variant}).or_default().push({path}::new);\n"
+            ));
+        }
+        encounters_mod_rs_source.push_str("        map\n    };\n}\n");
         std::fs::write(encounter_directory.join("mod.rs"), encounters_mod_rs_source)?;
         Ok(())
     }
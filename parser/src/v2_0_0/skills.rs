@@ -2,7 +2,7 @@ use heck::ToPascalCase;
 use models::v2_0_0::Tooltip;
 use std::path::PathBuf;
 
-use super::{JsonValue, ModuleName, StructName, tag_strlist};
+use super::{tag_strlist, JsonValue, ModuleName, StructName};
 
 pub struct JsonSkillFields {
     id: String,
@@ -151,6 +151,7 @@ impl SkillSourceBuilder {
             .as_array()
             .ok_or(anyhow::anyhow!("no data property on root object"))?;
         let mut skills_mod_rs_source = String::from("// @generated\n");
+        let mut struct_metadata_list = Vec::with_capacity(skills_as_json.len());
         for json_skill in skills_as_json {
             let name = json_skill["name"]
                 .as_str()
@@ -160,6 +161,7 @@ impl SkillSourceBuilder {
             let fields: JsonSkillFields = json_skill
                 .try_into()
                 .inspect_err(|e| eprintln!("cargo:warning=invalid json ({e}) {json_skill:?}"))?;
+            let id = fields.id.clone();
             let source = fields.to_source_code(&struct_name);
 
             let syntax_tree = syn::parse_str(&source)?;
@@ -170,8 +172,25 @@ impl SkillSourceBuilder {
             )?;
             skills_mod_rs_source.push_str(&format!(
                 "pub mod {module_name};pub use {module_name}::{struct_name};\n"
-            ))
+            ));
+            struct_metadata_list.push((module_name, struct_name, name, id));
         }
+        skills_mod_rs_source.push_str("lazy_static::lazy_static!{\n    pub static ref CONSTRUCT_SKILL_BY_NAME: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Skill> = std::collections::HashMap::from([\n");
+        for (module_name, struct_name, name, _) in &struct_metadata_list {
+            skills_mod_rs_source.push_str(&format!(
+                r#"        ("{name}", {module_name}::{struct_name}::new as fn() -> _),"#
+            ));
+            skills_mod_rs_source.push('\n');
+        }
+        skills_mod_rs_source.push_str("\n    ]);\n");
+        skills_mod_rs_source.push_str("    pub static ref CONSTRUCT_SKILL_BY_ID: std::collections::HashMap<&'static str, fn() -> models::v2_0_0::Skill> = std::collections::HashMap::from([\n");
+        for (module_name, struct_name, _, id) in &struct_metadata_list {
+            skills_mod_rs_source.push_str(&format!(
+                r#"        ("{id}", {module_name}::{struct_name}::new as fn() -> _),"#
+            ));
+            skills_mod_rs_source.push('\n');
+        }
+        skills_mod_rs_source.push_str("\n    ]);\n}\n");
         std::fs::write(skills_directory.join("mod.rs"), skills_mod_rs_source)?;
         Ok(())
     }
@@ -1,9 +1,27 @@
 use std::{rc::Rc, time::Duration};
 
-use models::v2_0_0::{Effect, Modifier, Percentage, PlayerTarget, TargetCondition, Tier, Tooltip};
+use models::v2_0_0::{
+    Action, Condition, DerivedValue, Effect, EffectEvent, EffectValue, GlobalEvent, Modifier,
+    Percentage, PlayerTarget, TargetCondition, Tier, Tooltip,
+};
 use tracing::Level;
 
-use crate::{CombatEvent, GameTicks, GlobalCardId, SkipReason};
+use crate::{
+    CombatEvent, GameTicks, GlobalCardId, SkipReason, TriggerId, CRIT_DAMAGE_MULTIPLIER,
+    MIN_COOLDOWN,
+};
+
+use super::{CardStats, CardTemplate};
+
+/// A tooltip that only applies while its condition holds on the owner's board.
+#[derive(Clone, Debug)]
+pub struct ConditionalTooltip {
+    pub condition: Condition,
+    pub tooltip: Tooltip,
+    pub active: bool,
+    /// Id of the tooltip's first time trigger, kept while the tooltip is switched off
+    pub trigger_id: TriggerId,
+}
 
 #[derive(Clone, Debug)]
 pub struct Card {
@@ -11,6 +29,11 @@ pub struct Card {
     pub inner: models::v2_0_0::Card,
     pub tier: Tier,
     pub cooldown_effects: Vec<Effect>,
+    pub card_used_triggers: Vec<(TargetCondition, Effect)>,
+    /// Effects fired when a card matching the condition is transformed
+    pub card_transformed_triggers: Vec<(TargetCondition, Effect)>,
+    pub first_time_triggers: Vec<(TriggerId, GlobalEvent, Effect)>,
+    pub action_triggers: Vec<(Vec<Action>, Effect)>,
     pub cooldown_counter: u128,
     pub freeze_ticks: GameTicks,
     pub slow_ticks: GameTicks,
@@ -18,7 +41,19 @@ pub struct Card {
     pub position: u8,
     pub owner: PlayerTarget,
     pub cooldown: GameTicks,
+    pub base_cooldown: Duration,
+    /// Seconds taken off the cooldown for the rest of the fight, negative when increased
+    pub cooldown_reduction_flat: f64,
+    pub cooldown_reduction_percentage: Percentage,
+    pub multicast: u32,
+    pub destroyed: bool,
+    pub is_skill: bool,
+    pub ammo: u32,
+    pub max_ammo: Option<u32>,
+    pub stats: CardStats,
+    pub tooltips: Vec<Tooltip>,
     pub modification_tooltips: Vec<Tooltip>,
+    pub conditional_tooltips: Vec<ConditionalTooltip>,
 
     pub freeze_guard: Option<Rc<tracing::span::EnteredSpan>>,
     pub slow_guard: Option<Rc<tracing::span::EnteredSpan>>,
@@ -27,6 +62,9 @@ pub struct Card {
 
 impl Card {
     pub fn tick(&mut self) -> Vec<CombatEvent> {
+        if self.destroyed {
+            return vec![];
+        }
         let cooldown_increment = match (self.slow_ticks.0 > 0, self.haste_ticks.0 > 0) {
             (true, false) => 1, // 0.5 * 2
             (false, true) => 4, // 2.0 * 2
@@ -53,32 +91,326 @@ impl Card {
             }
         }
 
+        let events = self.activate_if_ready();
+        if self.is_charged() {
+            // Out of ammo, the card stays charged until it is reloaded
+            return events;
+        }
+        self.cooldown_counter += cooldown_increment;
+        events
+    }
+
+    fn cooldown_threshold(&self) -> u128 {
+        self.cooldown.0 * 2
+    }
+
+    fn is_charged(&self) -> bool {
+        self.cooldown > GameTicks(0) && self.cooldown_counter > self.cooldown_threshold()
+    }
+
+    fn activate_if_ready(&mut self) -> Vec<CombatEvent> {
         let mut events: Vec<CombatEvent> = Vec::new();
-        if self.cooldown > GameTicks(0) {
-            let threshold = self.cooldown.0 * 2;
-            while self.cooldown_counter > threshold {
-                self.cooldown_counter -= threshold;
-                for effect in &self.cooldown_effects {
-                    let mut combat_events: Vec<CombatEvent> =
-                        self.effect_to_combat_events(effect.clone());
-                    events.append(&mut combat_events);
-                }
+        while self.is_charged() && self.has_ammo() {
+            self.cooldown_counter -= self.cooldown_threshold();
+            events.append(&mut self.use_card());
+        }
+        events
+    }
+
+    pub fn is_frozen(&self) -> bool {
+        self.freeze_ticks > GameTicks(0)
+    }
+
+    pub fn has_cooldown(&self) -> bool {
+        self.cooldown > GameTicks(0)
+    }
+
+    pub fn is_radiant(&self) -> bool {
+        self.tooltips
+            .iter()
+            .any(|t| matches!(t, Tooltip::StaticModifier(Modifier::Radiant)))
+    }
+
+    pub fn has_lifesteal(&self) -> bool {
+        self.tooltips
+            .iter()
+            .any(|t| matches!(t, Tooltip::StaticModifier(Modifier::Lifesteal)))
+    }
+
+    /// Destroys the card for the rest of the fight. It keeps its board position but no longer
+    /// ticks, triggers or can be targeted.
+    pub fn destroy(&mut self) {
+        tracing::event!(name: "destroy", Level::INFO, id = ?self.id_for_simulation);
+        self.destroyed = true;
+        let _ = self.freeze_guard.take();
+        let _ = self.slow_guard.take();
+        let _ = self.haste_guard.take();
+    }
+
+    /// Switches the conditional tooltip at `index` on or off. An active tooltip behaves like any
+    /// other tooltip of the card. Returns whether anything changed.
+    pub fn set_conditional_tooltip_active(&mut self, index: usize, active: bool) -> bool {
+        let Some(conditional) = self.conditional_tooltips.get_mut(index) else {
+            return false;
+        };
+        if conditional.active == active {
+            return false;
+        }
+        conditional.active = active;
+        let tooltip = conditional.tooltip.clone();
+        let trigger_id = conditional.trigger_id;
+        tracing::event!(
+            name: "conditional tooltip",
+            Level::INFO,
+            id = ?self.id_for_simulation,
+            ?tooltip,
+            ?active,
+        );
+        if active {
+            self.add_tooltip(tooltip, trigger_id);
+        } else {
+            self.remove_tooltip(&tooltip, trigger_id);
+        }
+        self.multicast = self.base_multicast();
+        self.stats.crit_chance = self.compute_crit_chance();
+        true
+    }
+
+    fn add_tooltip(&mut self, tooltip: Tooltip, trigger_id: TriggerId) {
+        match &tooltip {
+            Tooltip::When(EffectEvent::OnCooldown(e)) => self.cooldown_effects.push(e.clone()),
+            Tooltip::When(EffectEvent::OnCardUsed(c, e)) => {
+                self.card_used_triggers.push((c.clone(), e.clone()))
+            }
+            Tooltip::When(EffectEvent::OnCardTransformed(e)) => self
+                .card_transformed_triggers
+                .push((TargetCondition::IsSelf, e.clone())),
+            Tooltip::When(EffectEvent::OnFirstTime(g, e)) => {
+                self.first_time_triggers
+                    .push((trigger_id, g.clone(), e.clone()))
             }
+            Tooltip::When(EffectEvent::OnAction(a, e)) => {
+                self.action_triggers.push((a.clone(), e.clone()))
+            }
+            _ => {}
+        }
+        self.tooltips.push(tooltip);
+    }
+
+    fn remove_tooltip(&mut self, tooltip: &Tooltip, trigger_id: TriggerId) {
+        fn remove_first<T: PartialEq>(items: &mut Vec<T>, item: &T) {
+            if let Some(index) = items.iter().position(|i| i == item) {
+                items.remove(index);
+            }
+        }
+        match tooltip {
+            Tooltip::When(EffectEvent::OnCooldown(e)) => {
+                remove_first(&mut self.cooldown_effects, e)
+            }
+            Tooltip::When(EffectEvent::OnCardUsed(c, e)) => {
+                remove_first(&mut self.card_used_triggers, &(c.clone(), e.clone()))
+            }
+            Tooltip::When(EffectEvent::OnCardTransformed(e)) => remove_first(
+                &mut self.card_transformed_triggers,
+                &(TargetCondition::IsSelf, e.clone()),
+            ),
+            Tooltip::When(EffectEvent::OnFirstTime(g, e)) => remove_first(
+                &mut self.first_time_triggers,
+                &(trigger_id, g.clone(), e.clone()),
+            ),
+            Tooltip::When(EffectEvent::OnAction(a, e)) => {
+                remove_first(&mut self.action_triggers, &(a.clone(), e.clone()))
+            }
+            _ => {}
+        }
+        remove_first(&mut self.tooltips, tooltip);
+    }
+
+    /// Changes the cooldown for the rest of the fight. Percentage reductions add up and apply to
+    /// the base cooldown, flat reductions are taken off afterwards, and the result never drops
+    /// below `MIN_COOLDOWN`. Progress towards the old cooldown is kept; if it already completes the
+    /// new one, the card activates once on its next tick.
+    pub fn reduce_cooldown(&mut self, reduction: &EffectValue<f64>) {
+        if self.base_cooldown.is_zero() {
+            return;
+        }
+        match reduction {
+            EffectValue::Flat(seconds) => self.cooldown_reduction_flat += seconds,
+            EffectValue::Percentage(p) => {
+                self.cooldown_reduction_percentage = Percentage::from_fraction(
+                    self.cooldown_reduction_percentage.as_fraction()
+                        + Percentage::from_percentage_value(*p).as_fraction(),
+                )
+            }
+        }
+        let multiplier = (1.0 - self.cooldown_reduction_percentage.as_fraction()).max(0.0);
+        let seconds = (self.base_cooldown.as_secs_f64() * multiplier
+            - self.cooldown_reduction_flat)
+            .max(MIN_COOLDOWN.as_secs_f64());
+        self.cooldown = Duration::from_secs_f64(seconds).into();
+        if self.is_charged() {
+            self.cooldown_counter = self.cooldown_threshold() + 1;
+        }
+    }
+
+    /// Advances the cooldown by `duration`, activating the card right away if that completes it.
+    /// A charge never completes more than one cooldown, and a frozen card holds its charge until
+    /// it thaws.
+    pub fn charge(&mut self, duration: GameTicks) -> Vec<CombatEvent> {
+        tracing::event!(
+            name: "charge item",
+            Level::INFO,
+            id = ?self.id_for_simulation,
+            ?duration,
+        );
+        if self.cooldown == GameTicks(0) {
+            return vec![];
+        }
+        let threshold = self.cooldown_threshold();
+        self.cooldown_counter = match self.cooldown_counter + duration.0 * 2 {
+            counter if counter >= threshold => threshold + 1,
+            counter => counter,
+        };
+        if self.freeze_ticks.0 > 0 {
+            return vec![];
+        }
+        self.activate_if_ready()
+    }
+
+    /// Uses the card once per point of multicast. Each cast is announced by its own
+    /// `CombatEvent::CardUsed`, whose effects are produced by `cast` once the simulation has rolled
+    /// for crit. Every cast consumes one ammo and casting stops once the card runs dry.
+    pub fn use_card(&mut self) -> Vec<CombatEvent> {
+        let mut events: Vec<CombatEvent> = Vec::new();
+        for _ in 0..self.multicast.max(1) {
+            if !self.has_ammo() {
+                break;
+            }
+            if self.max_ammo.is_some() {
+                self.ammo -= 1;
+            }
+            events.push(CombatEvent::CardUsed(self.id_for_simulation));
         }
-        self.cooldown_counter += cooldown_increment;
         events
     }
 
+    /// Events of the cooldown effects of a single cast. Effects linked to the damage of the card
+    /// ride on the last damage event of the cast and resolve from the damage it deals. Without
+    /// damage in the cast they are derived from the card's stats.
+    pub fn cast(&self, crit: bool) -> Vec<CombatEvent> {
+        let (linked, mut events): (Vec<CombatEvent>, Vec<CombatEvent>) = self
+            .cooldown_effects
+            .iter()
+            .flat_map(|effect| self.effect_to_combat_events(effect.clone()))
+            .partition(CombatEvent::is_damage_linked);
+        let deals_damage = events
+            .iter()
+            .any(|e| matches!(e, CombatEvent::DealDamage(..)));
+        if deals_damage {
+            if let Some(CombatEvent::DealDamage(_, _, linked_events, _)) = events
+                .iter_mut()
+                .rev()
+                .find(|e| matches!(e, CombatEvent::DealDamage(..)))
+            {
+                linked_events.extend(linked);
+            }
+        } else {
+            events.extend(linked);
+        }
+        self.crit_events(events, crit)
+    }
+
+    /// Events of `effect`, multiplied by the crit damage multiplier of the card on a crit.
+    pub fn effect_events(&self, effect: Effect, crit: bool) -> Vec<CombatEvent> {
+        self.crit_events(self.effect_to_combat_events(effect), crit)
+    }
+
+    fn crit_events(&self, events: Vec<CombatEvent>, crit: bool) -> Vec<CombatEvent> {
+        match crit {
+            true => {
+                let multiplier = self.crit_damage_multiplier();
+                events.into_iter().map(|e| e.crit(multiplier)).collect()
+            }
+            false => events,
+        }
+    }
+
+    /// Multicast granted by the card's own tooltips. Modifiers that depend on the rest of the
+    /// board are resolved by the simulation.
+    pub fn base_multicast(&self) -> u32 {
+        let multicast = self
+            .tooltips
+            .iter()
+            .filter_map(|t| match t {
+                Tooltip::StaticModifier(Modifier::Multicast(m)) => Some(*m),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        let increased_multicast = self
+            .tooltips
+            .iter()
+            .filter_map(|t| match t {
+                Tooltip::StaticModifier(Modifier::IncreasedMulticast(DerivedValue::Constant(
+                    m,
+                ))) => Some(*m),
+                _ => None,
+            })
+            .sum::<u32>();
+        multicast + increased_multicast
+    }
+
+    pub fn has_ammo(&self) -> bool {
+        self.max_ammo.is_none() || self.ammo > 0
+    }
+
+    /// Reloads `amount` ammo, or up to max ammo when `None`, and returns the ammo restored.
+    pub fn reload(&mut self, amount: Option<u32>) -> u32 {
+        let Some(max_ammo) = self.max_ammo else {
+            return 0;
+        };
+        let missing = max_ammo.saturating_sub(self.ammo);
+        let restored = amount.map_or(missing, |amount| amount.min(missing));
+        tracing::event!(
+            name: "reload item",
+            Level::INFO,
+            id = ?self.id_for_simulation,
+            ?amount,
+            ?restored,
+        );
+        self.ammo += restored;
+        restored
+    }
+
+    pub fn increase_max_ammo(&mut self, amount: u32) {
+        if let Some(max_ammo) = self.max_ammo {
+            tracing::event!(
+                name: "increase max ammo",
+                Level::INFO,
+                id = ?self.id_for_simulation,
+                ?amount,
+            );
+            self.max_ammo = Some(max_ammo + amount);
+            self.ammo += amount;
+        }
+    }
+
     pub fn compute_cost(&self) -> u32 {
+        if self.is_skill {
+            return 0;
+        }
         let base_cost = self.tier.scale_cost(self.inner.size.base_cost());
-        let todo = true; //TODO check fi this applies
-                         // Tooltip::ConditionalModifier(condition, Modifier) => todo!(),
-        let todo = true; //TODO check if this applies
-                         // Tooltip::SellsForGold => todo!(), // this is empty in the dataset
+        // Tooltip::SellsForGold does not change the value, and it is empty in the dataset
         let modification_cost = self
             .tier
             .select(&self.inner.tiers)
             .iter()
+            .chain(
+                self.conditional_tooltips
+                    .iter()
+                    .filter(|c| c.active)
+                    .map(|c| &c.tooltip),
+            )
             .filter_map(|t| match t {
                 Tooltip::StaticModifier(Modifier::IncreasedValue(v)) => Some(*v),
                 _ => None,
@@ -123,14 +455,88 @@ impl Card {
         self.haste_ticks += duration
     }
 
+    /// Board spaces taken up by the card.
+    pub fn board_span(&self) -> std::ops::Range<u8> {
+        self.position..self.position + self.inner.size.board_spaces()
+    }
+
+    /// Board spans of this card and `other` when both sit on the same board. Skills sit outside
+    /// the board and have no position.
+    fn board_spans(&self, other: &Card) -> Option<(std::ops::Range<u8>, std::ops::Range<u8>)> {
+        (!self.is_skill && !other.is_skill && self.owner == other.owner)
+            .then(|| (self.board_span(), other.board_span()))
+    }
+
+    /// Turns this card into `inner` at `tier` for the rest of the fight. The card keeps its id,
+    /// position and the freeze, slow and haste on it, everything else comes from the new
+    /// definition. The copy starts its cooldown from zero and loses the stats gained during the
+    /// fight, like the item it copies would when placed on the board. Items of a different size
+    /// are refused, they would not fit the board span.
+    pub fn transform(&mut self, inner: models::v2_0_0::Card, tier: Tier) -> anyhow::Result<()> {
+        if inner.size != self.inner.size {
+            anyhow::bail!(
+                "card {} can not transform into {}, the sizes differ",
+                self.inner.name,
+                inner.name
+            );
+        }
+        let template = CardTemplate {
+            name: inner.name.to_string(),
+            tier,
+            modifications: vec![],
+        };
+        let mut card = template.create_card(
+            inner,
+            self.position,
+            self.owner,
+            self.id_for_simulation,
+            self.is_skill,
+        )?;
+        card.freeze_ticks = self.freeze_ticks;
+        card.slow_ticks = self.slow_ticks;
+        card.haste_ticks = self.haste_ticks;
+        card.freeze_guard = self.freeze_guard.take();
+        card.slow_guard = self.slow_guard.take();
+        card.haste_guard = self.haste_guard.take();
+        *self = card;
+        Ok(())
+    }
+
+    /// Whether `target_candidate` satisfies `condition`, seen from this card.
+    ///
+    /// Tags, size, cooldown and starting tier are read from the candidate, so "your weapons" or
+    /// "for each adjacent Tool" look at the cards being targeted or counted. Without a candidate
+    /// they fall back to this card, which is how conditions about the item itself are checked.
     pub fn matches(&self, condition: &TargetCondition, target_candidate: Option<&Card>) -> bool {
         match condition {
             TargetCondition::Always => true,
             TargetCondition::Never => false,
-            TargetCondition::HasCooldown => self.cooldown > GameTicks(0),
+            TargetCondition::HasCooldown => {
+                target_candidate.unwrap_or(self).cooldown > GameTicks(0)
+            }
             TargetCondition::Adjacent => target_candidate
-                .map(|t| self.owner == t.owner && self.position.abs_diff(t.position) == 1)
+                .and_then(|t| self.board_spans(t))
+                .map(|(this, other)| other.end == this.start || this.end == other.start)
+                .unwrap_or(false),
+            TargetCondition::AdjacentLeft => target_candidate
+                .and_then(|t| self.board_spans(t))
+                .map(|(this, other)| other.end == this.start)
+                .unwrap_or(false),
+            TargetCondition::AdjacentRight => target_candidate
+                .and_then(|t| self.board_spans(t))
+                .map(|(this, other)| this.end == other.start)
                 .unwrap_or(false),
+            TargetCondition::LeftOf => target_candidate
+                .and_then(|t| self.board_spans(t))
+                .map(|(this, other)| other.end <= this.start)
+                .unwrap_or(false),
+            TargetCondition::RightOf => target_candidate
+                .and_then(|t| self.board_spans(t))
+                .map(|(this, other)| this.end <= other.start)
+                .unwrap_or(false),
+            // Picking the leftmost or rightmost card needs the whole board, see
+            // `Simulation::targets_card`
+            TargetCondition::Leftmost | TargetCondition::Rightmost => target_candidate.is_some(),
             TargetCondition::IsSelf => target_candidate.map(|t| self == t).unwrap_or(false),
             TargetCondition::HasOwner(condition_owner) => match self.owner {
                 PlayerTarget::Player => target_candidate
@@ -140,8 +546,20 @@ impl Card {
                     .map(|t| t.owner.inverse() == *condition_owner)
                     .unwrap_or(false),
             },
-            TargetCondition::HasTag(tag) => self.inner.tags.iter().find(|t| *t == tag).is_some(),
-            TargetCondition::HasSize(size) => self.inner.size == *size,
+            TargetCondition::HasTag(tag) => target_candidate
+                .unwrap_or(self)
+                .inner
+                .tags
+                .iter()
+                .any(|t| t == tag),
+            TargetCondition::HasSize(size) => {
+                // Skills take up no board space, so they have no size
+                let card = target_candidate.unwrap_or(self);
+                !card.is_skill && card.inner.size == *size
+            }
+            TargetCondition::HasStartingTier(tier) => {
+                target_candidate.unwrap_or(self).inner.starting_tier == *tier
+            }
             TargetCondition::And(a, b) => {
                 self.matches(a, target_candidate) && self.matches(b, target_candidate)
             }
@@ -159,10 +577,11 @@ impl Card {
         }
     }
 
+    /// Crit chance granted by the card's own tooltips, enchantments included. Crit chance granted
+    /// by other cards is added by the simulation.
     pub fn compute_crit_chance(&self) -> Percentage {
         let fraction = self
-            .tier
-            .select(&self.inner.tiers)
+            .tooltips
             .iter()
             .filter_map(|t| match t {
                 Tooltip::StaticModifier(Modifier::CritChance(c)) => Some(c.as_fraction()),
@@ -172,47 +591,66 @@ impl Card {
         Percentage::from_fraction(fraction)
     }
 
+    /// How many times its normal value a crit of this card is worth. Crits deal double damage,
+    /// "double crit damage" doubles that to 4x and "quadruple crit damage" quadruples it to 8x.
+    /// The modifiers describe the card rather than add up, so several of them do not stack and
+    /// the strongest one applies.
+    pub fn crit_damage_multiplier(&self) -> u32 {
+        let bonus = self
+            .tooltips
+            .iter()
+            .filter_map(|t| match t {
+                Tooltip::StaticModifier(Modifier::DoubleCritDamage) => Some(2),
+                Tooltip::StaticModifier(Modifier::QuadrupleCritDamage) => Some(4),
+                _ => None,
+            })
+            .max()
+            .unwrap_or(1);
+        *CRIT_DAMAGE_MULTIPLIER * bonus
+    }
+
     pub fn effect_to_combat_events(&self, value: Effect) -> Vec<CombatEvent> {
         match value {
             Effect::DealDamage(player_target, damage) => {
                 vec![CombatEvent::DealDamage(
                     player_target,
-                    damage,
+                    self.stats.read_damage(damage),
+                    vec![],
                     self.id_for_simulation,
                 )]
             }
             Effect::Burn(player_target, burn) => {
                 vec![CombatEvent::ApplyBurn(
                     player_target,
-                    burn,
+                    self.stats.read_burn(burn),
                     self.id_for_simulation,
                 )]
             }
             Effect::Poison(player_target, poison) => {
                 vec![CombatEvent::ApplyPoison(
                     player_target,
-                    poison,
+                    self.stats.read_poison(poison),
                     self.id_for_simulation,
                 )]
             }
             Effect::Heal(player_target, heal) => {
                 vec![CombatEvent::Heal(
                     player_target,
-                    heal,
+                    self.stats.read_heal(heal),
                     self.id_for_simulation,
                 )]
             }
             Effect::Regen(player_target, regen) => {
                 vec![CombatEvent::Regen(
                     player_target,
-                    regen,
+                    self.stats.read_regen(regen),
                     self.id_for_simulation,
                 )]
             }
             Effect::Shield(player_target, shield) => {
                 vec![CombatEvent::ApplyShield(
                     player_target,
-                    shield,
+                    self.stats.read_shield(shield),
                     self.id_for_simulation,
                 )]
             }
@@ -224,6 +662,14 @@ impl Card {
                     self.id_for_simulation,
                 )]
             }
+            Effect::DamageImmunity(duration_seconds) => {
+                let duration: GameTicks = Duration::from_secs_f64(duration_seconds).into();
+                vec![CombatEvent::DamageImmunity(
+                    PlayerTarget::Player,
+                    duration,
+                    self.id_for_simulation,
+                )]
+            }
             Effect::Slow(target, duration_seconds) => {
                 let duration: GameTicks = Duration::from_secs_f64(duration_seconds).into();
                 vec![CombatEvent::Slow(target, duration, self.id_for_simulation)]
@@ -232,6 +678,62 @@ impl Card {
                 let duration: GameTicks = Duration::from_secs_f64(duration_seconds).into();
                 vec![CombatEvent::Haste(target, duration, self.id_for_simulation)]
             }
+            Effect::Charge(target, duration_seconds) => {
+                let duration: GameTicks = Duration::from_secs_f64(duration_seconds).into();
+                vec![CombatEvent::Charge(
+                    target,
+                    duration,
+                    self.id_for_simulation,
+                )]
+            }
+            Effect::Reload(target, amount) => {
+                vec![CombatEvent::Reload(target, amount, self.id_for_simulation)]
+            }
+            Effect::Use(target) | Effect::UseCard(target) => {
+                vec![CombatEvent::UseCard(target, self.id_for_simulation)]
+            }
+            Effect::Destroy(target) => {
+                vec![CombatEvent::Destroy(target, self.id_for_simulation)]
+            }
+            Effect::TransformIntoCopy(target, tier) => {
+                vec![CombatEvent::TransformIntoCopy(
+                    target,
+                    tier,
+                    self.id_for_simulation,
+                )]
+            }
+            Effect::CooldownReduction(target, reduction) => {
+                vec![CombatEvent::CooldownReduction(
+                    target,
+                    reduction,
+                    self.id_for_simulation,
+                )]
+            }
+            Effect::IncreaseDamage(target, amount) => {
+                vec![CombatEvent::IncreaseDamage(
+                    target,
+                    amount,
+                    self.id_for_simulation,
+                )]
+            }
+            Effect::GainShield(target, amount) => {
+                vec![CombatEvent::GainShield(
+                    target,
+                    amount,
+                    self.id_for_simulation,
+                )]
+            }
+            Effect::MultiEffect(effects) => effects
+                .into_iter()
+                .flat_map(|e| self.effect_to_combat_events(e))
+                .collect(),
+            Effect::IncreaseMaxAmmo(target, amount) => {
+                vec![CombatEvent::IncreaseMaxAmmo(
+                    target,
+                    amount,
+                    self.id_for_simulation,
+                )]
+            }
             _ => {
                 tracing::event!(Level::ERROR, ?value, "effect could not become combatevent");
                 vec![CombatEvent::Raw(format!("{value}"))]
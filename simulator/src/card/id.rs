@@ -1,6 +1,7 @@
 use std::sync::atomic::{AtomicU64, Ordering};
 
 static COUNTER: AtomicU64 = AtomicU64::new(0);
+static TRIGGER_COUNTER: AtomicU64 = AtomicU64::new(0);
 
 #[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct GlobalCardId(u64);
@@ -32,3 +33,20 @@ impl std::fmt::Debug for GlobalCardId {
         write!(f, "GlobalCardId(0x{:x})", self.0)
     }
 }
+
+/// Identifies a triggered effect of a card for as long as the card keeps it, so that effects
+/// firing at most once per fight stay latched when the card's other tooltips change.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct TriggerId(u64);
+
+impl TriggerId {
+    pub fn new() -> Self {
+        Self(TRIGGER_COUNTER.fetch_add(1, Ordering::SeqCst))
+    }
+}
+
+impl Default for TriggerId {
+    fn default() -> Self {
+        TriggerId::new()
+    }
+}
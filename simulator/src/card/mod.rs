@@ -1,9 +1,11 @@
 pub mod base_card;
 pub mod id;
 pub mod modification;
+pub mod stats;
 pub mod template;
 
 pub use base_card::*;
 pub use id::*;
 pub use modification::*;
+pub use stats::*;
 pub use template::*;
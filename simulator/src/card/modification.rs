@@ -1,6 +1,8 @@
-use models::v2_0_0::{Modifier, Percentage, Tooltip};
+use models::v2_0_0::{Modifier, Percentage, TargetCondition, Tooltip};
 use serde::Deserialize;
 
+use super::{Card, CardStats};
+
 #[derive(Clone, Debug, Deserialize)]
 pub enum CardModification {
     Enchanted(models::v2_0_0::Enchantment),
@@ -26,13 +28,57 @@ impl CardModification {
             CardModification::Value(value) => {
                 vec![Tooltip::StaticModifier(Modifier::IncreasedValue(*value))]
             }
-            CardModification::Burn(_) => todo!(),
-            CardModification::Poison(_) => todo!(),
-            CardModification::Shield(_) => todo!(),
-            CardModification::Heal(_) => todo!(),
-            CardModification::Damage(_) => todo!(),
-            CardModification::Cooldown(..) => todo!(),
-            CardModification::Crit(..) => todo!(),
+            CardModification::Cooldown(reduction) => {
+                vec![Tooltip::StaticModifier(Modifier::GrantCooldownReduction(
+                    TargetCondition::IsSelf,
+                    *reduction,
+                ))]
+            }
+            CardModification::Crit(chance) => {
+                vec![Tooltip::StaticModifier(Modifier::CritChance(*chance))]
+            }
+            CardModification::Burn(..)
+            | CardModification::Poison(..)
+            | CardModification::Shield(..)
+            | CardModification::Heal(..)
+            | CardModification::Damage(..) => vec![],
+        }
+    }
+
+    /// Rejects modifications that have nothing to modify on `card`, such as extra damage on an
+    /// item that deals none, or that are out of range.
+    pub fn validate(&self, card: &Card) -> anyhow::Result<()> {
+        if let CardModification::Cooldown(p) | CardModification::Crit(p) = self {
+            if !(0.0..=1.0).contains(&p.as_fraction()) {
+                anyhow::bail!("{self:?} is not between 0% and 100%");
+            }
+        }
+        let applies = match self {
+            CardModification::Enchanted(..)
+            | CardModification::Value(..)
+            | CardModification::Crit(..) => true,
+            CardModification::Burn(..) => card.stats.burn > 0,
+            CardModification::Poison(..) => card.stats.poison > 0,
+            CardModification::Shield(..) => card.stats.shield > 0,
+            CardModification::Heal(..) => card.stats.heal > 0,
+            CardModification::Damage(..) => card.stats.damage > 0,
+            CardModification::Cooldown(..) => !card.base_cooldown.is_zero(),
+        };
+        if !applies {
+            anyhow::bail!("{self:?} does not apply to {}", card.inner.name);
+        }
+        Ok(())
+    }
+
+    /// Adds the flat stat bonuses on top of the values printed on the card.
+    pub fn apply_to_stats(&self, stats: &mut CardStats) {
+        match self {
+            CardModification::Burn(burn) => stats.burn += burn,
+            CardModification::Poison(poison) => stats.poison += poison,
+            CardModification::Shield(shield) => stats.shield += shield,
+            CardModification::Heal(heal) => stats.heal += heal,
+            CardModification::Damage(damage) => stats.damage += damage,
+            _ => {}
         }
     }
 }
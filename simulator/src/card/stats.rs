@@ -0,0 +1,164 @@
+use models::v2_0_0::{
+    DerivedValue, Effect, EffectEvent, EffectValue, Modifier, Percentage, Tooltip,
+};
+
+/// Numbers a card's effects read during the fight. They start out as the first constant of each
+/// kind printed on the card and are changed by in-fight buffs such as "your weapons gain +N damage
+/// for the fight". Every effect keeps its own constant and moves with its stat, see `read_damage`.
+#[derive(Clone, Debug, Default)]
+pub struct CardStats {
+    pub damage: u32,
+    pub shield: u32,
+    pub heal: u32,
+    pub burn: u32,
+    pub poison: u32,
+    pub regen: u32,
+    pub value: u32,
+    pub crit_chance: Percentage,
+    /// Percentage bonus applied on top of `damage` ("this has double damage" is 100)
+    pub damage_bonus: u32,
+    printed: PrintedStats,
+}
+
+/// The stats as printed on the card, before modifications and buffs.
+#[derive(Clone, Debug, Default)]
+struct PrintedStats {
+    damage: u32,
+    shield: u32,
+    heal: u32,
+    burn: u32,
+    poison: u32,
+    regen: u32,
+}
+
+impl CardStats {
+    pub fn new(tooltips: &[Tooltip], value: u32) -> Self {
+        let mut stats = Self {
+            value,
+            ..Default::default()
+        };
+        let effects: Vec<&Effect> = tooltips
+            .iter()
+            .filter_map(|t| match t {
+                Tooltip::When(
+                    EffectEvent::OnCooldown(e)
+                    | EffectEvent::OnFightStart(e)
+                    | EffectEvent::OnCardUsed(_, e)
+                    | EffectEvent::OnCrit(_, e)
+                    | EffectEvent::OnAction(_, e)
+                    | EffectEvent::OnFirstTime(_, e),
+                ) => Some(e),
+                _ => None,
+            })
+            .collect();
+        for effect in effects {
+            stats.init_from_effect(effect);
+        }
+        for tooltip in tooltips {
+            if let Tooltip::StaticModifier(Modifier::WeaponDamage(EffectValue::Percentage(p))) =
+                tooltip
+            {
+                stats.damage_bonus += p;
+            }
+        }
+        stats.printed = PrintedStats {
+            damage: stats.damage,
+            shield: stats.shield,
+            heal: stats.heal,
+            burn: stats.burn,
+            poison: stats.poison,
+            regen: stats.regen,
+        };
+        stats
+    }
+
+    /// The first constant of each kind becomes the base value of that stat.
+    fn init_from_effect(&mut self, effect: &Effect) {
+        let (stat, value) = match effect {
+            Effect::DealDamage(_, DerivedValue::Constant(v)) => (&mut self.damage, *v),
+            Effect::Shield(_, DerivedValue::Constant(v)) => (&mut self.shield, *v),
+            Effect::Heal(_, DerivedValue::Constant(v)) => (&mut self.heal, *v),
+            Effect::Burn(_, DerivedValue::Constant(v)) => (&mut self.burn, *v),
+            Effect::Poison(_, DerivedValue::Constant(v)) => (&mut self.poison, *v),
+            Effect::Regen(_, DerivedValue::Constant(v)) => (&mut self.regen, *v),
+            Effect::MultiEffect(effects) => {
+                for e in effects {
+                    self.init_from_effect(e);
+                }
+                return;
+            }
+            _ => return,
+        };
+        if *stat == 0 {
+            *stat = value;
+        }
+    }
+
+    pub fn effective_damage(&self) -> u32 {
+        self.effective_damage_of(self.printed.damage)
+    }
+
+    /// Damage of an effect printed with `constant`, after what the damage stat has gained or lost
+    /// since and the damage bonus.
+    pub fn effective_damage_of(&self, constant: u32) -> u32 {
+        let damage = Self::shift(constant, self.damage, self.printed.damage);
+        damage + damage * self.damage_bonus / 100
+    }
+
+    /// The value of a damage effect. Constants printed on the card follow the damage stat, derived
+    /// values are left to the simulation.
+    pub fn read_damage(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        match value {
+            DerivedValue::Constant(v) => DerivedValue::Constant(self.effective_damage_of(v)),
+            derived => derived,
+        }
+    }
+
+    pub fn read_shield(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        Self::read(value, self.shield, self.printed.shield)
+    }
+
+    pub fn read_heal(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        Self::read(value, self.heal, self.printed.heal)
+    }
+
+    pub fn read_burn(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        Self::read(value, self.burn, self.printed.burn)
+    }
+
+    pub fn read_poison(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        Self::read(value, self.poison, self.printed.poison)
+    }
+
+    pub fn read_regen(&self, value: DerivedValue<u32>) -> DerivedValue<u32> {
+        Self::read(value, self.regen, self.printed.regen)
+    }
+
+    fn read(value: DerivedValue<u32>, stat: u32, printed: u32) -> DerivedValue<u32> {
+        match value {
+            DerivedValue::Constant(v) => DerivedValue::Constant(Self::shift(v, stat, printed)),
+            derived => derived,
+        }
+    }
+
+    /// Moves `constant` by the difference between the current and the printed value of its stat.
+    fn shift(constant: u32, stat: u32, printed: u32) -> u32 {
+        (constant + stat).saturating_sub(printed)
+    }
+
+    pub fn increase_damage(&mut self, value: &EffectValue<u32>) {
+        match value {
+            EffectValue::Flat(v) => self.damage += v,
+            EffectValue::Percentage(p) => self.damage += self.damage * p / 100,
+        }
+    }
+
+    pub fn increase_shield(&mut self, value: &EffectValue<f64>) {
+        match value {
+            EffectValue::Flat(v) => self.shield += v.round() as u32,
+            EffectValue::Percentage(p) => {
+                self.shield += (self.shield as f64 * p / 100.0).round() as u32
+            }
+        }
+    }
+}
@@ -1,11 +1,14 @@
 use std::time::Duration;
 
-use models::v2_0_0::{Effect, EffectEvent, Modifier, PlayerTarget, Tier, Tooltip};
+use models::v2_0_0::{
+    Action, Effect, EffectEvent, GlobalEvent, Modifier, Percentage, PlayerTarget, TargetCondition,
+    Tier, Tooltip,
+};
 use serde::Deserialize;
 
 use crate::GameTicks;
 
-use super::{Card, CardModification, GlobalCardId};
+use super::{Card, CardModification, CardStats, ConditionalTooltip, GlobalCardId, TriggerId};
 
 #[derive(Clone, Debug, Deserialize)]
 pub struct CardTemplate {
@@ -26,14 +29,37 @@ impl CardTemplate {
             *gamedata::v2_0_0::cards::CONSTRUCT_CARD_BY_NAME
                 .get(self.name.as_str())
                 .ok_or(anyhow::anyhow!("unknown card {:?}", &self.name))?;
-        let inner = create_item();
-        let todo = true; //TODO optimize
-        let mut tooltips: Vec<Tooltip> = self.tier.select(&inner.tiers).iter().cloned().collect();
-        for derived_tooltips in self.modifications.iter().map(|m| m.derive_tooltips(&inner)) {
-            for tooltip in derived_tooltips.into_iter() {
-                tooltips.push(tooltip.clone())
-            }
+        self.create_card(create_item(), position, owner, id, false)
+    }
+
+    /// Creates a skill of `owner`. Skills take part in the fight like cards but sit outside the
+    /// board, so they take up no space and cannot be targeted.
+    pub fn create_skill(&self, owner: PlayerTarget, id: GlobalCardId) -> anyhow::Result<Card> {
+        let create_skill: fn() -> models::v2_0_0::Skill =
+            *gamedata::v2_0_0::skills::CONSTRUCT_SKILL_BY_NAME
+                .get(self.name.as_str())
+                .ok_or(anyhow::anyhow!("unknown skill {:?}", &self.name))?;
+        if !self.modifications.is_empty() {
+            anyhow::bail!("skill {} cannot be modified", self.name);
         }
+        self.create_card(create_skill().into(), 0, owner, id, true)
+    }
+
+    pub(super) fn create_card(
+        &self,
+        inner: models::v2_0_0::Card,
+        position: u8,
+        owner: PlayerTarget,
+        id: GlobalCardId,
+        is_skill: bool,
+    ) -> anyhow::Result<Card> {
+        let modification_tooltips: Vec<Tooltip> = self
+            .modifications
+            .iter()
+            .flat_map(|m| m.derive_tooltips(&inner))
+            .collect();
+        let mut tooltips: Vec<Tooltip> = self.tier.select(&inner.tiers).to_vec();
+        tooltips.extend(modification_tooltips.iter().cloned());
         if tooltips.len() == 0 {
             anyhow::bail!("no tooltips on card {} of tier {:?}", self.name, self.tier);
         }
@@ -44,6 +70,39 @@ impl CardTemplate {
                 _ => None,
             })
             .collect();
+        let card_used_triggers: Vec<(TargetCondition, Effect)> = tooltips
+            .iter()
+            .flat_map(|t| match t {
+                Tooltip::When(EffectEvent::OnCardUsed(c, e)) => Some((c.clone(), e.clone())),
+                _ => None,
+            })
+            .collect();
+        // "When this is transformed" is the only transform trigger, the item itself
+        let card_transformed_triggers: Vec<(TargetCondition, Effect)> = tooltips
+            .iter()
+            .flat_map(|t| match t {
+                Tooltip::When(EffectEvent::OnCardTransformed(e)) => {
+                    Some((TargetCondition::IsSelf, e.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let first_time_triggers: Vec<(TriggerId, GlobalEvent, Effect)> = tooltips
+            .iter()
+            .flat_map(|t| match t {
+                Tooltip::When(EffectEvent::OnFirstTime(g, e)) => {
+                    Some((TriggerId::new(), g.clone(), e.clone()))
+                }
+                _ => None,
+            })
+            .collect();
+        let action_triggers: Vec<(Vec<Action>, Effect)> = tooltips
+            .iter()
+            .flat_map(|t| match t {
+                Tooltip::When(EffectEvent::OnAction(a, e)) => Some((a.clone(), e.clone())),
+                _ => None,
+            })
+            .collect();
         let cooldown = tooltips
             .iter()
             .find_map(|t| match t {
@@ -53,30 +112,77 @@ impl CardTemplate {
                 _ => None,
             })
             .unwrap_or(Duration::from_secs(0));
+        // Conditional tooltips start inactive, the simulation evaluates them against the board
+        let conditional_tooltips: Vec<ConditionalTooltip> = tooltips
+            .iter()
+            .flat_map(|t| match t {
+                Tooltip::Conditional(condition, tooltip) => {
+                    Some((condition, tooltip.as_ref().clone()))
+                }
+                Tooltip::ConditionalModifier(condition, modifier) => {
+                    Some((condition, Tooltip::StaticModifier(modifier.clone())))
+                }
+                _ => None,
+            })
+            .map(|(condition, tooltip)| ConditionalTooltip {
+                condition: condition.clone(),
+                tooltip,
+                active: false,
+                trigger_id: TriggerId::new(),
+            })
+            .collect();
+        let max_ammo = tooltips.iter().find_map(|t| match t {
+            Tooltip::StaticModifier(Modifier::Ammo(a)) => Some(*a),
+            _ => None,
+        });
+
+        // The size of a skill is a placeholder, skills have no value
+        let value = match is_skill {
+            true => 0,
+            false => self.tier.scale_cost(inner.size.base_cost()),
+        };
+        let stats = CardStats::new(&tooltips, value);
 
         tracing::event!(name: "register card", tracing::Level::INFO, ?id, ?position, ?tooltips, name = ?inner.name);
 
-        Ok(Card {
+        let mut card = Card {
             position,
             owner,
             cooldown: cooldown.into(),
+            base_cooldown: cooldown,
+            cooldown_reduction_flat: 0.0,
+            cooldown_reduction_percentage: Percentage::default(),
+            multicast: 1,
+            destroyed: false,
+            is_skill,
+            ammo: max_ammo.unwrap_or(0),
+            max_ammo,
+            stats,
             tier: self.tier,
             id_for_simulation: id,
             cooldown_effects,
+            card_used_triggers,
+            card_transformed_triggers,
+            first_time_triggers,
+            action_triggers,
             cooldown_counter: 0,
             freeze_ticks: GameTicks::default(),
             slow_ticks: GameTicks::default(),
             haste_ticks: GameTicks::default(),
-            modification_tooltips: self
-                .modifications
-                .iter()
-                .map(|m| m.derive_tooltips(&inner))
-                .flatten()
-                .collect(),
+            modification_tooltips,
+            conditional_tooltips,
+            tooltips,
             inner,
             freeze_guard: None,
             slow_guard: None,
             haste_guard: None,
-        })
+        };
+        for modification in &self.modifications {
+            modification.validate(&card)?;
+            modification.apply_to_stats(&mut card.stats);
+        }
+        card.multicast = card.base_multicast();
+        card.stats.crit_chance = card.compute_crit_chance();
+        Ok(card)
     }
 }
@@ -1,4 +1,9 @@
-use models::v2_0_0::{CardTarget, DerivedValue, PlayerTarget};
+use std::sync::Arc;
+
+use models::v2_0_0::{
+    Action, CardDerivedProperty, CardTarget, DerivedValue, EffectValue, PlayerTarget, Tag,
+    TargetCondition, Tier,
+};
 
 use crate::{GameTicks, GlobalCardId};
 
@@ -11,17 +16,126 @@ pub enum SkipReason {
 pub enum CombatEvent {
     Raw(String),
     Skip(SkipReason),
-    DealDamage(PlayerTarget, DerivedValue<u32>, GlobalCardId),
+    CardUsed(GlobalCardId),
+    Crit(GlobalCardId),
+    /// Damage, followed by the events of the same use whose value is linked to the damage dealt
+    DealDamage(
+        PlayerTarget,
+        DerivedValue<u32>,
+        Vec<CombatEvent>,
+        GlobalCardId,
+    ),
     ApplyBurn(PlayerTarget, DerivedValue<u32>, GlobalCardId),
     ApplyPoison(PlayerTarget, DerivedValue<u32>, GlobalCardId),
     ApplyShield(PlayerTarget, DerivedValue<u32>, GlobalCardId),
     Heal(PlayerTarget, DerivedValue<u32>, GlobalCardId),
+    /// Healing from a lifesteal item, equal to the damage it just dealt
+    Lifesteal(PlayerTarget, u32, GlobalCardId),
     Freeze(CardTarget, GameTicks, GlobalCardId),
     Regen(PlayerTarget, DerivedValue<u32>, GlobalCardId),
+    DamageImmunity(PlayerTarget, GameTicks, GlobalCardId),
     Slow(CardTarget, GameTicks, GlobalCardId),
     Haste(CardTarget, GameTicks, GlobalCardId),
+    Charge(CardTarget, GameTicks, GlobalCardId),
+    Reload(CardTarget, Option<u32>, GlobalCardId),
+    IncreaseMaxAmmo(CardTarget, EffectValue<u32>, GlobalCardId),
+    UseCard(CardTarget, GlobalCardId),
+    Destroy(CardTarget, GlobalCardId),
+    TransformIntoCopy(CardTarget, Tier, GlobalCardId),
+    /// A card turned into the named item at the given tier, with the tags it had before
+    Transformed(GlobalCardId, &'static str, Tier, Arc<[Tag]>),
+    CooldownReduction(CardTarget, EffectValue<f64>, GlobalCardId),
+    IncreaseDamage(CardTarget, EffectValue<u32>, GlobalCardId),
+    GainShield(CardTarget, EffectValue<f64>, GlobalCardId),
+    Sandstorm(u32),
     Tick(u128),
 }
 
+impl CombatEvent {
+    /// The kinds of action this event is for the player causing it. Burn and poison only count
+    /// against the enemy, healing, regen and shield only on oneself. A transformation counts as
+    /// transforming each tag of the item transformed away.
+    pub fn actions(&self) -> Vec<Action> {
+        let action = match self {
+            CombatEvent::Haste(..) => Action::Haste,
+            CombatEvent::Slow(..) => Action::Slow,
+            CombatEvent::Freeze(..) => Action::Freeze,
+            CombatEvent::Charge(..) => Action::Charge,
+            CombatEvent::Reload(..) => Action::Reload,
+            CombatEvent::ApplyBurn(PlayerTarget::Opponent, ..) => Action::Burn,
+            CombatEvent::ApplyPoison(PlayerTarget::Opponent, ..) => Action::Poison,
+            CombatEvent::Heal(PlayerTarget::Player, ..)
+            | CombatEvent::Lifesteal(PlayerTarget::Player, ..) => Action::Heal,
+            CombatEvent::Regen(PlayerTarget::Player, ..) => Action::Regen,
+            CombatEvent::ApplyShield(PlayerTarget::Player, ..) => Action::Shield,
+            CombatEvent::Transformed(.., tags) => {
+                return tags.iter().copied().map(Action::Transform).collect()
+            }
+            _ => return vec![],
+        };
+        vec![action]
+    }
+
+    /// The event of a critical hit, with its value multiplied by `multiplier`. Events linked to
+    /// a damage event follow the damage dealt, which has already crit.
+    pub fn crit(self, multiplier: u32) -> Self {
+        self.map_value(|value| match value {
+            DerivedValue::Constant(v) => DerivedValue::Constant(v * multiplier),
+            DerivedValue::FromCard(target, property, modifier) => {
+                DerivedValue::FromCard(target, property, modifier * multiplier as f32)
+            }
+            DerivedValue::FromPlayer(target, property, modifier) => {
+                DerivedValue::FromPlayer(target, property, modifier * multiplier as f32)
+            }
+        })
+    }
+
+    /// Whether the value of this event is a share of the damage of its source, as in "shield
+    /// equal to this item's damage".
+    pub fn is_damage_linked(&self) -> bool {
+        match self {
+            CombatEvent::ApplyBurn(_, v, _)
+            | CombatEvent::ApplyPoison(_, v, _)
+            | CombatEvent::ApplyShield(_, v, _)
+            | CombatEvent::Heal(_, v, _)
+            | CombatEvent::Regen(_, v, _) => matches!(
+                v,
+                DerivedValue::FromCard(
+                    CardTarget(_, TargetCondition::IsSelf, _),
+                    CardDerivedProperty::Damage,
+                    _
+                )
+            ),
+            _ => false,
+        }
+    }
+
+    /// The damage-linked event with its value resolved from the damage `dealt` by its use.
+    pub fn with_damage_dealt(self, dealt: u32) -> Self {
+        self.map_value(|value| match value {
+            DerivedValue::FromCard(
+                CardTarget(_, TargetCondition::IsSelf, _),
+                CardDerivedProperty::Damage,
+                modifier,
+            ) => DerivedValue::Constant((dealt as f32 * modifier) as u32),
+            value => value,
+        })
+    }
+
+    fn map_value(self, f: impl FnOnce(DerivedValue<u32>) -> DerivedValue<u32>) -> Self {
+        match self {
+            CombatEvent::DealDamage(t, v, linked, id) => {
+                CombatEvent::DealDamage(t, f(v), linked, id)
+            }
+            CombatEvent::ApplyBurn(t, v, id) => CombatEvent::ApplyBurn(t, f(v), id),
+            CombatEvent::ApplyPoison(t, v, id) => CombatEvent::ApplyPoison(t, f(v), id),
+            CombatEvent::ApplyShield(t, v, id) => CombatEvent::ApplyShield(t, f(v), id),
+            CombatEvent::Heal(t, v, id) => CombatEvent::Heal(t, f(v), id),
+            CombatEvent::Regen(t, v, id) => CombatEvent::Regen(t, f(v), id),
+            event => event,
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub struct TaggedCombatEvent(pub PlayerTarget, pub CombatEvent);
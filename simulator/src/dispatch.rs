@@ -1,21 +1,30 @@
 use models::v2_0_0::PlayerTarget;
 
-use crate::{Card, GameTicks, GlobalCardId};
+use crate::{Card, DamageRecord, GameTicks, GlobalCardId};
 
 #[derive(Clone, Debug)]
 pub struct CardSummary {
     pub id: GlobalCardId,
     pub name: String,
     pub owner: PlayerTarget,
+    pub ammo: u32,
+    pub max_ammo: Option<u32>,
 }
 
 impl std::fmt::Display for CardSummary {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "Card<id={}, name={}, owner={}>",
-            self.id, self.name, self.owner
-        )
+        match self.max_ammo {
+            Some(max_ammo) => write!(
+                f,
+                "Card<id={}, name={}, owner={}, ammo={}/{max_ammo}>",
+                self.id, self.name, self.owner, self.ammo
+            ),
+            None => write!(
+                f,
+                "Card<id={}, name={}, owner={}>",
+                self.id, self.name, self.owner
+            ),
+        }
     }
 }
 
@@ -25,6 +34,8 @@ impl From<&Card> for CardSummary {
             id: value.id_for_simulation,
             name: value.inner.name.to_string(),
             owner: value.owner,
+            ammo: value.ammo,
+            max_ammo: value.max_ammo,
         }
     }
 }
@@ -35,6 +46,8 @@ impl From<&mut Card> for CardSummary {
             id: value.id_for_simulation,
             name: value.inner.name.to_string(),
             owner: value.owner,
+            ammo: value.ammo,
+            max_ammo: value.max_ammo,
         }
     }
 }
@@ -45,7 +58,23 @@ pub enum DispatchableEvent {
     Error(String),
     Warning(String),
     Tick,
+    CardUsed(CardSummary),
+    CardCrit(CardSummary),
+    CardTriggered(CardSummary),
     CardFrozen(CardSummary, GameTicks),
     CardHasted(CardSummary, GameTicks),
     CardSlowed(CardSummary, GameTicks),
+    CardCharged(CardSummary, GameTicks),
+    CardReloaded(CardSummary, u32),
+    CardMaxAmmoIncreased(CardSummary, u32),
+    CardDestroyed(CardSummary),
+    CardTransformed(CardSummary),
+    CardConditionChanged(CardSummary, bool),
+    CardCooldownChanged(CardSummary, GameTicks),
+    CardDamageIncreased(CardSummary, u32),
+    CardShieldIncreased(CardSummary, u32),
+    DamageTaken(PlayerTarget, DamageRecord),
+    DamageImmunityStarted(PlayerTarget, GameTicks),
+    DamageImmunityEnded(PlayerTarget),
+    Sandstorm(u32),
 }
@@ -2,4 +2,5 @@
 pub enum SimulationDrawType {
     Timeout,
     SimultaneousDefeat,
+    Sandstorm,
 }
@@ -1,8 +1,8 @@
 use tracing::Level;
 
-use crate::{GlobalCardId, TICKS_PER_SECOND};
+use crate::{GameTicks, GlobalCardId, TICKS_PER_SECOND};
 
-use super::{PlayerHealth, PlayerTemplate};
+use super::{DamageMitigation, DamageRecord, PlayerHealth, PlayerTemplate};
 
 #[derive(Clone, Debug)]
 pub struct Player {
@@ -11,9 +11,15 @@ pub struct Player {
     pub poison_stacks: i64,
     pub burn_stacks: i64,
     pub regeneration_stacks: i64,
+    /// Regen granted by the `Regen` modifiers of the player's items, part of
+    /// `regeneration_stacks`
+    pub item_regen: i64,
     pub card_ids: Vec<GlobalCardId>,
+    pub skill_ids: Vec<GlobalCardId>,
     pub template: PlayerTemplate,
     pub dot_counter: usize,
+    pub immunity_ticks: GameTicks,
+    pub gold: u32,
 }
 
 impl Player {
@@ -21,18 +27,55 @@ impl Player {
         self.burn_stacks += amount as i64
     }
 
-    pub fn take_damage(&mut self, amount: u32) {
-        tracing::event!(Level::INFO, ?amount, "player take damage");
-        let mut remaining = amount as i64;
+    pub fn is_immune(&self) -> bool {
+        self.immunity_ticks > GameTicks(0)
+    }
 
-        if self.shield_stacks > 0 {
-            let deduct = std::cmp::min(self.shield_stacks, remaining);
-            self.shield_stacks -= deduct;
-            remaining -= deduct;
+    /// Overlapping immunity windows do not stack, the longer one wins.
+    pub fn grant_immunity(&mut self, duration: GameTicks) {
+        tracing::event!(Level::INFO, ?duration, "player damage immunity");
+        self.immunity_ticks = std::cmp::max(self.immunity_ticks, duration);
+    }
+
+    /// Resolves direct damage: mitigation, then shield absorption, then health loss.
+    pub fn take_damage(&mut self, amount: u32, mitigation: DamageMitigation) -> DamageRecord {
+        tracing::event!(Level::INFO, ?amount, "player take damage");
+        let mut record = DamageRecord {
+            pre_mitigation: amount,
+            ..Default::default()
+        };
+        if self.is_immune() {
+            record.mitigated = amount;
+            return record;
         }
 
-        if remaining > 0 {
-            self.health -= remaining;
+        let remaining = mitigation.apply(amount);
+        record.mitigated = amount - remaining;
+        self.absorb_damage(remaining, &mut record);
+        record
+    }
+
+    /// Resolves sandstorm damage. The sandstorm is not an attack, so neither damage immunity nor
+    /// the mitigation of the player's items reduce it, but shield still absorbs it.
+    pub fn take_sandstorm_damage(&mut self, amount: u32) -> DamageRecord {
+        tracing::event!(Level::INFO, ?amount, "player take sandstorm damage");
+        let mut record = DamageRecord {
+            pre_mitigation: amount,
+            ..Default::default()
+        };
+        self.absorb_damage(amount, &mut record);
+        record
+    }
+
+    /// Takes `amount` from the shield first and from health second.
+    fn absorb_damage(&mut self, amount: u32, record: &mut DamageRecord) {
+        let absorbed = std::cmp::min(self.shield_stacks.max(0), amount as i64);
+        self.shield_stacks -= absorbed;
+        record.absorbed_by_shield = absorbed as u32;
+        record.health_lost = amount - record.absorbed_by_shield;
+
+        if record.health_lost > 0 {
+            self.health -= record.health_lost as i64;
         }
     }
 
@@ -65,6 +108,12 @@ impl Player {
             return;
         }
 
+        // burn keeps decaying while immune, it just doesn't hurt
+        if self.is_immune() {
+            self.burn_stacks -= 1;
+            return;
+        }
+
         let base_damage = self.burn_stacks as f64;
         let damage = if self.shield_stacks > 0 {
             (base_damage * 0.5).round() as i64
@@ -90,10 +139,15 @@ impl Player {
             for _ in 0..2 {
                 self.burn_tick();
             }
-            self.health -= self.poison_stacks;
+            if !self.is_immune() {
+                self.health -= self.poison_stacks;
+            }
             self.health += self.regeneration_stacks;
         }
         self.dot_counter += 1;
+        if self.is_immune() {
+            self.immunity_ticks -= 1;
+        }
     }
 }
 
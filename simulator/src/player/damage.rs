@@ -0,0 +1,36 @@
+use models::v2_0_0::Percentage;
+
+/// Damage reduction collected from a player's board, applied before shield absorption.
+#[derive(Copy, Clone, Debug, Default)]
+pub struct DamageMitigation {
+    pub percentage: Percentage,
+    pub flat: u32,
+}
+
+impl DamageMitigation {
+    /// Percentage reduction is applied first, flat reduction second.
+    pub fn apply(&self, amount: u32) -> u32 {
+        let fraction = self.percentage.as_fraction().clamp(0.0, 1.0);
+        let reduced = (amount as f64 * (1.0 - fraction)).round() as u32;
+        reduced.saturating_sub(self.flat)
+    }
+}
+
+/// Outcome of one instance of direct damage, broken down by resolution stage.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct DamageRecord {
+    pub pre_mitigation: u32,
+    pub mitigated: u32,
+    pub absorbed_by_shield: u32,
+    pub health_lost: u32,
+}
+
+impl std::fmt::Display for DamageRecord {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Damage({} - {} mitigated - {} shielded = {} health lost)",
+            self.pre_mitigation, self.mitigated, self.absorbed_by_shield, self.health_lost
+        )
+    }
+}
@@ -1,7 +1,9 @@
 pub mod base_player;
+pub mod damage;
 pub mod health;
 pub mod template;
 
 pub use base_player::*;
+pub use damage::*;
 pub use health::*;
 pub use template::*;
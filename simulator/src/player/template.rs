@@ -1,6 +1,6 @@
 use serde::Deserialize;
 
-use crate::{CardTemplate, GlobalCardId};
+use crate::{CardTemplate, GameTicks, GlobalCardId};
 
 use super::{Player, PlayerHealth};
 
@@ -13,6 +13,8 @@ pub struct PlayerTemplate {
     pub card_templates: Vec<CardTemplate>,
     #[serde(default, rename = "skills")]
     pub skill_templates: Vec<CardTemplate>,
+    #[serde(default)]
+    pub gold: u32,
 }
 
 impl PlayerTemplate {
@@ -23,8 +25,12 @@ impl PlayerTemplate {
             poison_stacks: 0,
             burn_stacks: 0,
             regeneration_stacks: self.regen,
+            item_regen: 0,
             dot_counter: 0,
+            immunity_ticks: GameTicks::default(),
+            gold: self.gold,
             card_ids,
+            skill_ids: vec![],
             template: self,
         })
     }
@@ -1,6 +1,6 @@
 use std::time::Duration;
 
-use crate::{CombatEvent, Player, SimulationDrawType, TaggedCombatEvent};
+use crate::{CardSummary, CombatEvent, Player, SimulationDrawType, TaggedCombatEvent};
 
 #[derive(Debug)]
 pub struct SimulationResultInner {
@@ -8,6 +8,9 @@ pub struct SimulationResultInner {
     pub duration: Duration,
     pub player: Player,
     pub opponent: Player,
+    pub sandstorm: bool,
+    pub cards: Vec<CardSummary>,
+    pub skills: Vec<CardSummary>,
 }
 
 #[derive(Debug)]
@@ -23,12 +26,17 @@ impl SimulationResult {
             SimulationResult::Victory(r)
             | SimulationResult::Defeat(r)
             | SimulationResult::Draw(SimulationDrawType::Timeout, r)
-            | SimulationResult::Draw(SimulationDrawType::SimultaneousDefeat, r) => r,
+            | SimulationResult::Draw(SimulationDrawType::SimultaneousDefeat, r)
+            | SimulationResult::Draw(SimulationDrawType::Sandstorm, r) => r,
         }
     }
 
     pub fn short_str(&self) -> String {
         match self {
+            SimulationResult::Victory(inner) if inner.sandstorm => {
+                "Victory (Sandstorm)".to_string()
+            }
+            SimulationResult::Defeat(inner) if inner.sandstorm => "Defeat (Sandstorm)".to_string(),
             SimulationResult::Victory(..) => "Victory".to_string(),
             SimulationResult::Defeat(..) => "Defeat".to_string(),
             SimulationResult::Draw(draw_type, ..) => format!("Draw ({draw_type:?})"),
@@ -40,6 +48,12 @@ impl std::fmt::Display for SimulationResult {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         let inner = self.inner_ref();
         let mut res = match self {
+            SimulationResult::Victory(..) if inner.sandstorm => {
+                format!("[{:?}] Victory by sandstorm\n", inner.duration)
+            }
+            SimulationResult::Defeat(..) if inner.sandstorm => {
+                format!("[{:?}] Defeat by sandstorm\n", inner.duration)
+            }
             SimulationResult::Victory(..) => {
                 format!("[{:?}] Victory\n", inner.duration)
             }
@@ -50,6 +64,9 @@ impl std::fmt::Display for SimulationResult {
             SimulationResult::Draw(SimulationDrawType::SimultaneousDefeat, ..) => {
                 format!("[{:?}] Draw by simultaneous defeat\n", inner.duration,)
             }
+            SimulationResult::Draw(SimulationDrawType::Sandstorm, ..) => {
+                format!("[{:?}] Draw by sandstorm\n", inner.duration)
+            }
         };
         let events = match self {
             SimulationResult::Victory(i) => &i.events,
@@ -1,16 +1,23 @@
-use std::time::Instant;
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::{Duration, Instant},
+};
 
 use indexmap::IndexMap;
 use models::v2_0_0::{
-    CardDerivedProperty, DerivedValue, Effect, Modifier, PlayerTarget, TargetCondition, Tooltip,
+    CardDerivedProperty, CardTarget, Condition, DerivedValue, Effect, EffectEvent, EffectValue,
+    GlobalEvent, Modifier, Percentage, PlayerDerivedProperty, PlayerTarget, Tag, TargetCondition,
+    TargetSelection, Tier, Tooltip,
 };
 use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
 use tracing::Level;
 
 use crate::{
-    Card, CardSummary, CombatEvent, DispatchableEvent, GameTicks, GlobalCardId, Player,
-    SimulationDrawType, SimulationResult, SimulationResultInner, SimulationTemplate,
-    TaggedCombatEvent, NUMBER_OF_BOARD_SPACES, SIMULATION_TICK_COUNT,
+    Card, CardSummary, CardTemplate, CombatEvent, DamageMitigation, DamageRecord,
+    DispatchableEvent, GameTicks, GlobalCardId, Player, SandstormTemplate, SimulationDrawType,
+    SimulationResult, SimulationResultInner, SimulationTemplate, TaggedCombatEvent, TriggerId,
+    DURATION_BEFORE_SANDSTORM, MAX_TRIGGERS_PER_CARD_PER_TICK, NUMBER_OF_BOARD_SPACES,
+    SIMULATION_TICK_COUNT,
 };
 
 #[derive(Clone, Debug)]
@@ -20,6 +27,9 @@ pub struct Simulation {
     pub event_sender: Option<std::sync::mpsc::Sender<DispatchableEvent>>,
     pub cards: IndexMap<GlobalCardId, Card>,
     pub ticks: u128,
+    pub triggers_this_tick: HashMap<GlobalCardId, usize>,
+    pub first_time_latches: HashSet<TriggerId>,
+    pub sandstorm: SandstormTemplate,
 }
 
 impl TryFrom<SimulationTemplate> for Simulation {
@@ -60,22 +70,61 @@ impl TryFrom<SimulationTemplate> for Simulation {
             anyhow::bail!("player board too large ({position})")
         }
 
+        let player_skills =
+            Self::create_skills(&template.player.skill_templates, PlayerTarget::Player)?;
+        let opponent_skills =
+            Self::create_skills(&template.opponent.skill_templates, PlayerTarget::Opponent)?;
+
         let player_card_ids: Vec<GlobalCardId> = player_cards.keys().cloned().collect();
         let opponent_card_ids: Vec<GlobalCardId> = opponent_cards.keys().cloned().collect();
+        let player_skill_ids: Vec<GlobalCardId> = player_skills.keys().cloned().collect();
+        let opponent_skill_ids: Vec<GlobalCardId> = opponent_skills.keys().cloned().collect();
         let mut cards = player_cards;
-        cards.extend(opponent_cards.into_iter());
+        cards.extend(opponent_cards);
+        cards.extend(player_skills);
+        cards.extend(opponent_skills);
 
-        Ok(Self {
+        let mut simulation = Self {
             cards,
             player: template.player.create_player(player_card_ids)?,
             opponent: template.opponent.create_player(opponent_card_ids)?,
             event_sender: None,
             ticks: 0,
-        })
+            triggers_this_tick: HashMap::new(),
+            first_time_latches: HashSet::new(),
+            sandstorm: template.sandstorm,
+        };
+        simulation.player.skill_ids = player_skill_ids;
+        simulation.opponent.skill_ids = opponent_skill_ids;
+        simulation.update_multicast();
+        simulation.apply_board_modifiers(true);
+        simulation.update_conditional_tooltips();
+        Ok(simulation)
     }
 }
 
 impl Simulation {
+    fn create_skills(
+        templates: &[CardTemplate],
+        owner: PlayerTarget,
+    ) -> anyhow::Result<IndexMap<GlobalCardId, Card>> {
+        let mut skills: IndexMap<GlobalCardId, Card> = IndexMap::new();
+        for template in templates {
+            if skills
+                .values()
+                .any(|skill| skill.inner.name == template.name)
+            {
+                anyhow::bail!("duplicate {owner} skill {:?}", template.name)
+            }
+            let id = GlobalCardId::default();
+            let skill = template.create_skill(owner, id).map_err(|error| {
+                anyhow::anyhow!("unable to parse {owner} skill template {template:?}: {error}")
+            })?;
+            skills.insert(id, skill);
+        }
+        Ok(skills)
+    }
+
     pub fn get_cards_by_owner(&self, owner: PlayerTarget) -> &Vec<GlobalCardId> {
         match owner {
             PlayerTarget::Player => &self.player.card_ids,
@@ -100,7 +149,9 @@ impl Simulation {
                 .cards
                 .iter()
                 .by_ref()
-                .filter(|(_, c)| source_card.matches(condition, Some(c)))
+                .filter(|(_, c)| {
+                    !c.destroyed && !c.is_skill && self.targets_card(source_card, condition, c)
+                })
                 .map(|(id, _)| id)
                 .cloned()
                 .collect();
@@ -109,13 +160,501 @@ impl Simulation {
             self.cards
                 .iter()
                 .by_ref()
-                .filter(|(_, c)| source_card.matches(condition, Some(c)))
+                .filter(|(_, c)| {
+                    !c.destroyed && !c.is_skill && self.targets_card(source_card, condition, c)
+                })
                 .map(|(id, _)| id)
                 .cloned()
                 .collect()
         }
     }
 
+    /// Whether `condition`, held by `source`, matches `candidate`. `Leftmost` and `Rightmost`
+    /// only match the outermost card on the candidate's board among those matching the rest of
+    /// the condition.
+    pub fn targets_card(
+        &self,
+        source: &Card,
+        condition: &TargetCondition,
+        candidate: &Card,
+    ) -> bool {
+        if !source.matches(condition, Some(candidate)) {
+            return false;
+        }
+        let leftmost = condition.contains(&TargetCondition::Leftmost);
+        let rightmost = condition.contains(&TargetCondition::Rightmost);
+        if !leftmost && !rightmost {
+            return true;
+        }
+        self.get_cards_by_owner(candidate.owner)
+            .iter()
+            .filter_map(|id| self.cards.get(id))
+            .filter(|c| !c.destroyed && source.matches(condition, Some(c)))
+            .all(|c| {
+                (!leftmost || c.position >= candidate.position)
+                    && (!rightmost || c.position <= candidate.position)
+            })
+    }
+
+    /// Picks up to `target.number_of_targets()` of the cards `target` matches from `source_id`
+    /// and for which `eligible` holds, following the target's selection policy. Random selection
+    /// prefers the candidates for which `preferred` holds, so that an effect spreads over cards
+    /// it does not affect yet. Ordered policies ignore the preference and break ties randomly.
+    fn select_targets(
+        &self,
+        source_id: &GlobalCardId,
+        target: &CardTarget,
+        eligible: impl Fn(&Card) -> bool,
+        preferred: impl Fn(&Card) -> bool,
+        rng: &mut StdRng,
+    ) -> Vec<GlobalCardId> {
+        let candidates = self
+            .get_cards_by_target(source_id, target.target_condition())
+            .into_iter()
+            .filter(|id| self.cards.get(id).is_some_and(&eligible));
+        let selection = target.selection();
+        let (mut first, mut second): (Vec<_>, Vec<_>) = match selection {
+            TargetSelection::Random => {
+                candidates.partition(|id| self.cards.get(id).is_some_and(&preferred))
+            }
+            _ => (candidates.collect(), vec![]),
+        };
+        first.shuffle(rng);
+        second.shuffle(rng);
+        if selection != TargetSelection::Random {
+            first.sort_by_key(|id| {
+                self.cards
+                    .get(id)
+                    .map_or(i128::MAX, |card| match selection {
+                        TargetSelection::LowestCooldown => card.cooldown.0 as i128,
+                        TargetSelection::HighestCooldown => -(card.cooldown.0 as i128),
+                        TargetSelection::HighestValue => -(card.stats.value as i128),
+                        TargetSelection::HighestDamage => -(card.stats.effective_damage() as i128),
+                        TargetSelection::Leftmost => card.position as i128,
+                        TargetSelection::Random => 0,
+                    })
+            });
+        }
+        first
+            .into_iter()
+            .chain(second)
+            .take(target.number_of_targets())
+            .collect()
+    }
+
+    /// Recomputes the multicast of every card, including modifiers that depend on the rest of
+    /// the board.
+    pub fn update_multicast(&mut self) {
+        let multicasts: Vec<(GlobalCardId, u32)> = self
+            .cards
+            .iter()
+            .map(|(id, card)| {
+                let derived_multicast = card
+                    .tooltips
+                    .iter()
+                    .filter_map(|t| match t {
+                        Tooltip::StaticModifier(Modifier::IncreasedMulticast(
+                            v @ (DerivedValue::FromCard(..) | DerivedValue::FromPlayer(..)),
+                        )) => self.derive_value(v.clone(), id).ok(),
+                        _ => None,
+                    })
+                    .sum::<f32>();
+                (
+                    *id,
+                    card.base_multicast() + derived_multicast.max(0.0) as u32,
+                )
+            })
+            .collect();
+        for (id, multicast) in multicasts {
+            if let Some(card) = self.cards.get_mut(&id) {
+                card.multicast = multicast;
+            }
+        }
+    }
+
+    /// Applies, or with `applied == false` reverts, the modifiers every card still on the board
+    /// grants to the rest of its board.
+    fn apply_board_modifiers(&mut self, applied: bool) {
+        let modifiers: Vec<(GlobalCardId, Modifier)> = self
+            .cards
+            .iter()
+            .filter(|(_, card)| !card.destroyed)
+            .flat_map(|(id, card)| {
+                card.tooltips.iter().filter_map(move |t| match t {
+                    Tooltip::StaticModifier(m) => Some((*id, m.clone())),
+                    _ => None,
+                })
+            })
+            .collect();
+        for (source_id, modifier) in modifiers {
+            self.apply_board_modifier(&source_id, &modifier, applied);
+        }
+    }
+
+    /// Applies, or with `applied == false` reverts, a modifier that `source_id` grants to other
+    /// cards: a flat `WeaponDamage` bonus goes to the weapons of its owner and
+    /// `GrantCooldownReduction` to the cards it matches. Modifiers the simulation reads live,
+    /// like crit chance or multicast, need no bookkeeping here.
+    fn apply_board_modifier(
+        &mut self,
+        source_id: &GlobalCardId,
+        modifier: &Modifier,
+        applied: bool,
+    ) {
+        match modifier {
+            Modifier::WeaponDamage(EffectValue::Flat(damage)) => {
+                let weapons = TargetCondition::HasOwner(PlayerTarget::Player)
+                    & TargetCondition::HasTag(Tag::Weapon);
+                for id in self.get_cards_by_target(source_id, &weapons) {
+                    if let Some(card) = self.cards.get_mut(&id) {
+                        card.stats.damage = match applied {
+                            true => card.stats.damage + damage,
+                            false => card.stats.damage.saturating_sub(*damage),
+                        };
+                    }
+                }
+            }
+            Modifier::GrantCooldownReduction(condition, p) => {
+                let reduction = match applied {
+                    true => p.as_percentage(),
+                    false => -p.as_percentage(),
+                };
+                for id in self.get_cards_by_target(source_id, condition) {
+                    if let Some(card) = self.cards.get_mut(&id) {
+                        card.reduce_cooldown(&EffectValue::Percentage(reduction));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Evaluates the conditional tooltips of every card against its owner's board and switches
+    /// them on or off. Runs when the fight starts and whenever the board changes.
+    pub fn update_conditional_tooltips(&mut self) {
+        let mut changes: Vec<(GlobalCardId, usize, bool)> = Vec::new();
+        for (id, card) in self.cards.iter().filter(|(_, card)| !card.destroyed) {
+            for (index, conditional) in card.conditional_tooltips.iter().enumerate() {
+                let holds = self.card_condition_holds(id, &conditional.condition);
+                if conditional.active != holds {
+                    changes.push((*id, index, holds));
+                }
+            }
+        }
+        for (id, index, active) in changes {
+            let Some(card_mut) = self.cards.get_mut(&id) else {
+                continue;
+            };
+            if !card_mut.set_conditional_tooltip_active(index, active) {
+                continue;
+            }
+            let summary = CardSummary::from(&*card_mut);
+            let tooltip = card_mut.conditional_tooltips[index].tooltip.clone();
+            if let Tooltip::StaticModifier(modifier) = tooltip {
+                self.apply_board_modifier(&id, &modifier, active);
+            }
+            self.dispatch_event(&DispatchableEvent::CardConditionChanged(summary, active));
+        }
+        self.update_multicast();
+        self.update_item_regen();
+    }
+
+    /// Recomputes the regen every player gets from the `Regen` modifiers of their items and
+    /// adjusts their regen by the difference.
+    pub fn update_item_regen(&mut self) {
+        for owner in [PlayerTarget::Player, PlayerTarget::Opponent] {
+            let player = match owner {
+                PlayerTarget::Player => &self.player,
+                PlayerTarget::Opponent => &self.opponent,
+            };
+            let item_regen: i64 = player
+                .card_ids
+                .iter()
+                .chain(player.skill_ids.iter())
+                .filter(|id| self.cards.get(*id).is_some_and(|card| !card.destroyed))
+                .flat_map(|id| {
+                    self.cards[id].tooltips.iter().filter_map(|t| match t {
+                        Tooltip::StaticModifier(Modifier::Regen(DerivedValue::Constant(r))) => {
+                            Some(*r as i64)
+                        }
+                        Tooltip::StaticModifier(Modifier::Regen(v)) => self
+                            .derive_value(v.clone(), id)
+                            .ok()
+                            .map(|r| r.max(0.0) as i64),
+                        _ => None,
+                    })
+                })
+                .sum();
+            let player = match owner {
+                PlayerTarget::Player => &mut self.player,
+                PlayerTarget::Opponent => &mut self.opponent,
+            };
+            player.regeneration_stacks += item_regen - player.item_regen;
+            player.item_regen = item_regen;
+        }
+    }
+
+    /// Destroys the card `id` for the rest of the fight. The modifiers it grants to other cards
+    /// are reverted first.
+    pub fn destroy_card(&mut self, id: &GlobalCardId) {
+        let Some(card) = self.cards.get(id) else {
+            return;
+        };
+        let modifiers: Vec<Modifier> = card
+            .tooltips
+            .iter()
+            .filter_map(|t| match t {
+                Tooltip::StaticModifier(m) => Some(m.clone()),
+                _ => None,
+            })
+            .collect();
+        for modifier in modifiers {
+            self.apply_board_modifier(id, &modifier, false);
+        }
+        if let Some(card_mut) = self.cards.get_mut(id) {
+            card_mut.destroy();
+            let summary = CardSummary::from(card_mut);
+            self.dispatch_event(&DispatchableEvent::CardDestroyed(summary));
+        }
+    }
+
+    /// Turns the card `id` into `inner` at `tier`, see `Card::transform`. Board modifiers are
+    /// reverted around the swap, so the old card stops granting its modifiers while the new one
+    /// grants and receives them.
+    pub fn transform_card(
+        &mut self,
+        id: &GlobalCardId,
+        inner: models::v2_0_0::Card,
+        tier: Tier,
+    ) -> anyhow::Result<()> {
+        if !self.cards.contains_key(id) {
+            anyhow::bail!("no card {id} to transform");
+        }
+        self.apply_board_modifiers(false);
+        let transformed = self
+            .cards
+            .get_mut(id)
+            .map_or(Ok(()), |card| card.transform(inner, tier));
+        self.apply_board_modifiers(true);
+        transformed?;
+        self.update_conditional_tooltips();
+        Ok(())
+    }
+
+    /// Crit chance of a card including the crit chance granted to it by the rest of the board.
+    pub fn crit_chance(&self, id: &GlobalCardId) -> Percentage {
+        let Some(card) = self.cards.get(id) else {
+            return Percentage::default();
+        };
+        let granted = self
+            .cards
+            .values()
+            .filter(|source| !source.destroyed)
+            .flat_map(|source| {
+                source.tooltips.iter().filter_map(move |t| match t {
+                    Tooltip::StaticModifier(Modifier::GrantCritChance(condition, chance))
+                        if self.targets_card(source, condition, card) =>
+                    {
+                        Some(chance.as_fraction())
+                    }
+                    _ => None,
+                })
+            })
+            .sum::<f64>();
+        Percentage::from_fraction((card.stats.crit_chance.as_fraction() + granted).clamp(0.0, 1.0))
+    }
+
+    /// Fires the `OnCardUsed` effects of every card reacting to the use of `used_id`, see
+    /// `trigger_limited`.
+    fn trigger_card_used(
+        &mut self,
+        used_id: &GlobalCardId,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        self.trigger_card_reactions(used_id, |card| &card.card_used_triggers, rng)
+    }
+
+    /// Fires the `OnCardTransformed` effects of every card reacting to the transformation of
+    /// `transformed_id`. Called before the swap, the triggers belong to the item that is
+    /// transformed away.
+    fn trigger_card_transformed(
+        &mut self,
+        transformed_id: &GlobalCardId,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        self.trigger_card_reactions(transformed_id, |card| &card.card_transformed_triggers, rng)
+    }
+
+    /// Fires the `triggers` of every card whose condition targets `subject_id`.
+    fn trigger_card_reactions(
+        &mut self,
+        subject_id: &GlobalCardId,
+        triggers: fn(&Card) -> &Vec<(TargetCondition, Effect)>,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        let Some(subject) = self.cards.get(subject_id) else {
+            return vec![];
+        };
+        let triggered: Vec<(GlobalCardId, Effect)> = self
+            .cards
+            .iter()
+            .filter(|(_, listener)| !listener.destroyed)
+            .flat_map(|(id, listener)| {
+                triggers(listener)
+                    .iter()
+                    .filter(|(condition, _)| self.targets_card(listener, condition, subject))
+                    .map(|(_, effect)| (*id, effect.clone()))
+            })
+            .collect();
+
+        self.trigger_limited(triggered, rng)
+    }
+
+    /// Fires the `OnAction` effects of the cards of the player performing `event`, if the event
+    /// is an action.
+    fn trigger_action(
+        &mut self,
+        event: &TaggedCombatEvent,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        let TaggedCombatEvent(owner, event) = event;
+        let performed = event.actions();
+        if performed.is_empty() {
+            return vec![];
+        }
+        let triggered: Vec<(GlobalCardId, Effect)> = self
+            .cards
+            .iter()
+            .filter(|(_, listener)| listener.owner == *owner && !listener.destroyed)
+            .flat_map(|(id, listener)| {
+                listener
+                    .action_triggers
+                    .iter()
+                    .filter(|(actions, _)| actions.iter().any(|a| performed.contains(a)))
+                    .map(|(_, effect)| (*id, effect.clone()))
+            })
+            .collect();
+        self.trigger_limited(triggered, rng)
+    }
+
+    /// Fires triggered effects. A card stops reacting once it has triggered
+    /// `MAX_TRIGGERS_PER_CARD_PER_TICK` times in a tick, which ends trigger chains that would
+    /// otherwise loop forever.
+    fn trigger_limited(
+        &mut self,
+        triggered: Vec<(GlobalCardId, Effect)>,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        let mut events = Vec::new();
+        for (listener_id, effect) in triggered {
+            let count = self.triggers_this_tick.entry(listener_id).or_default();
+            if *count >= *MAX_TRIGGERS_PER_CARD_PER_TICK {
+                self.dispatch_event(&DispatchableEvent::Warning(format!(
+                    "card {listener_id} reached the trigger limit for this tick"
+                )));
+                continue;
+            }
+            *count += 1;
+            events.extend(self.trigger_effect(&listener_id, effect, rng));
+        }
+        events
+    }
+
+    /// Fires the `OnFirstTime` effects whose global event has just happened. Each effect fires
+    /// at most once per fight.
+    fn trigger_first_time(&mut self, rng: &mut StdRng) -> Vec<TaggedCombatEvent> {
+        let triggered: Vec<(GlobalCardId, TriggerId, Effect)> = self
+            .cards
+            .iter()
+            .filter(|(_, card)| !card.destroyed)
+            .flat_map(|(id, card)| {
+                card.first_time_triggers
+                    .iter()
+                    .filter(|(trigger_id, global_event, _)| {
+                        !self.first_time_latches.contains(trigger_id)
+                            && self.global_event_holds(card.owner, global_event)
+                    })
+                    .map(|(trigger_id, _, effect)| (*id, *trigger_id, effect.clone()))
+            })
+            .collect();
+
+        let mut events = Vec::new();
+        for (id, trigger_id, effect) in triggered {
+            self.first_time_latches.insert(trigger_id);
+            events.extend(self.trigger_effect(&id, effect, rng));
+        }
+        events
+    }
+
+    /// Whether `global_event` has happened from the point of view of a card owned by `owner`.
+    pub fn global_event_holds(&self, owner: PlayerTarget, global_event: &GlobalEvent) -> bool {
+        let (player, enemy) = match owner {
+            PlayerTarget::Player => (&self.player, &self.opponent),
+            PlayerTarget::Opponent => (&self.opponent, &self.player),
+        };
+        match global_event {
+            GlobalEvent::PlayerFallsBelowHpPercentage(percentage) => {
+                (player.health.fraction() as f64) * 100.0 < *percentage
+            }
+            GlobalEvent::EnemyFallsBelowHpPercentage(percentage) => {
+                (enemy.health.fraction() as f64) * 100.0 < *percentage
+            }
+        }
+    }
+
+    /// Rolls a crit for a card reacting to something other than its own use and returns the
+    /// events of the triggered effect.
+    fn trigger_effect(
+        &mut self,
+        listener_id: &GlobalCardId,
+        effect: Effect,
+        rng: &mut StdRng,
+    ) -> Vec<TaggedCombatEvent> {
+        let did_crit = rng.random::<f64>() < self.crit_chance(listener_id).as_fraction();
+        let Some(listener) = self.cards.get_mut(listener_id) else {
+            return vec![];
+        };
+        let owner = listener.owner;
+        let events = listener
+            .effect_events(effect, did_crit)
+            .into_iter()
+            .map(|e| TaggedCombatEvent(owner, e))
+            .collect();
+        let summary = CardSummary::from(listener);
+        self.dispatch_event(&DispatchableEvent::CardTriggered(summary));
+        events
+    }
+
+    /// Resolves the value of a burn, poison, shield, heal or regen event. Values linked to the
+    /// damage dealt by the same use arrive resolved, anything else is derived from the board.
+    /// Crits are already part of the event.
+    pub fn resolve_effect_value(
+        &self,
+        value: &DerivedValue<u32>,
+        source_id: &GlobalCardId,
+    ) -> anyhow::Result<u32> {
+        match value {
+            DerivedValue::Constant(v) => Ok(*v),
+            _ => Ok(self.derive_value(value.clone(), source_id)? as u32),
+        }
+    }
+
+    pub fn card_summaries(&self) -> Vec<CardSummary> {
+        self.cards
+            .values()
+            .filter(|card| !card.is_skill)
+            .map(CardSummary::from)
+            .collect()
+    }
+
+    pub fn skill_summaries(&self) -> Vec<CardSummary> {
+        self.cards
+            .values()
+            .filter(|card| card.is_skill)
+            .map(CardSummary::from)
+            .collect()
+    }
+
     pub fn with_channel(mut self, sender: std::sync::mpsc::Sender<DispatchableEvent>) -> Self {
         self.event_sender = Some(sender);
         self
@@ -133,10 +672,60 @@ impl Simulation {
         }
     }
 
+    fn is_sandstorm_tick(tick: u128) -> bool {
+        GameTicks(tick) >= GameTicks::from(*DURATION_BEFORE_SANDSTORM)
+    }
+
+    pub fn is_sandstorm_active(&self) -> bool {
+        Self::is_sandstorm_tick(self.ticks)
+    }
+
+    /// Whether the last simulated tick happened during the sandstorm.
+    fn fought_in_sandstorm(&self) -> bool {
+        self.ticks
+            .checked_sub(1)
+            .is_some_and(Self::is_sandstorm_tick)
+    }
+
+    /// Damage dealt to both players by the sandstorm on the current tick, if any. The damage
+    /// ramps up by a fixed increment every time the sandstorm strikes.
+    pub fn sandstorm_damage(&self) -> Option<u32> {
+        if !self.is_sandstorm_active() {
+            return None;
+        }
+        let elapsed = self.ticks - GameTicks::from(*DURATION_BEFORE_SANDSTORM).0;
+        let interval = GameTicks::from(Duration::from_millis(self.sandstorm.interval_ms))
+            .0
+            .max(1);
+        if !elapsed.is_multiple_of(interval) {
+            return None;
+        }
+        let strikes = (elapsed / interval) as u32;
+        Some(self.sandstorm.base_damage + strikes * self.sandstorm.damage_increment)
+    }
+
     fn tick(&mut self) -> Vec<TaggedCombatEvent> {
         let mut events: Vec<TaggedCombatEvent> = Vec::new();
+        self.triggers_this_tick.clear();
+        let was_immune = (self.player.is_immune(), self.opponent.is_immune());
         tracing::info_span!("player tick").in_scope(|| self.player.tick());
         tracing::info_span!("opponent tick").in_scope(|| self.opponent.tick());
+        if was_immune.0 && !self.player.is_immune() {
+            self.dispatch_event(&DispatchableEvent::DamageImmunityEnded(
+                PlayerTarget::Player,
+            ));
+        }
+        if was_immune.1 && !self.opponent.is_immune() {
+            self.dispatch_event(&DispatchableEvent::DamageImmunityEnded(
+                PlayerTarget::Opponent,
+            ));
+        }
+        if let Some(damage) = self.sandstorm_damage() {
+            events.push(TaggedCombatEvent(
+                PlayerTarget::Player,
+                CombatEvent::Sandstorm(damage),
+            ));
+        }
         for (_, card) in &mut self.cards {
             for e in card.tick() {
                 events.push(TaggedCombatEvent(card.owner, e));
@@ -150,9 +739,39 @@ impl Simulation {
         events
     }
 
-    fn apply_event(&mut self, event: &TaggedCombatEvent, rng: &mut StdRng) -> anyhow::Result<()> {
+    /// Applies a single event and returns the events it caused, which are applied within the same
+    /// tick.
+    fn apply_event(
+        &mut self,
+        event: &TaggedCombatEvent,
+        rng: &mut StdRng,
+    ) -> anyhow::Result<Vec<TaggedCombatEvent>> {
+        let mut follow_up_events: Vec<TaggedCombatEvent> = Vec::new();
         match event {
             TaggedCombatEvent(.., CombatEvent::Skip(..)) => {}
+            TaggedCombatEvent(owner, CombatEvent::CardUsed(id)) => {
+                // A use queued before its card was destroyed earlier in the tick does not happen
+                if self.cards.get(id).is_some_and(|card| !card.destroyed) {
+                    let did_crit = rng.random::<f64>() < self.crit_chance(id).as_fraction();
+                    if let Some(card) = self.cards.get_mut(id) {
+                        let cast = card.cast(did_crit);
+                        let summary = CardSummary::from(card);
+                        self.dispatch_event(&DispatchableEvent::CardUsed(summary));
+                        if did_crit {
+                            follow_up_events
+                                .push(TaggedCombatEvent(*owner, CombatEvent::Crit(*id)));
+                        }
+                        follow_up_events
+                            .extend(cast.into_iter().map(|e| TaggedCombatEvent(*owner, e)));
+                    }
+                    follow_up_events.extend(self.trigger_card_used(id, rng));
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::Crit(id)) => {
+                if let Some(card) = self.cards.get(id) {
+                    self.dispatch_event(&DispatchableEvent::CardCrit(CardSummary::from(card)));
+                }
+            }
             TaggedCombatEvent(.., CombatEvent::Raw(s)) => {
                 self.dispatch_event(&DispatchableEvent::Warning(format!(
                     "raw event skipped: {s}"
@@ -160,37 +779,44 @@ impl Simulation {
             }
             TaggedCombatEvent(
                 owner,
-                CombatEvent::DealDamage(player_target, damage_derivable, source_id),
+                CombatEvent::DealDamage(player_target, damage_derivable, linked, source_id),
             ) => {
-                if let Some(card) = self.cards.get(source_id) {
-                    let did_crit = card.compute_crit_chance().as_fraction() < rng.random::<f64>();
+                if self.cards.contains_key(source_id) {
                     let damage = match damage_derivable {
                         DerivedValue::Constant(p) => *p,
                         _ => self.derive_value(damage_derivable.clone(), source_id)? as u32,
                     };
-                    let damage = if did_crit {
-                        let todo = true; //TODO what about increased crit dmg
-                        damage + damage
-                    } else {
-                        damage
+                    let record = match owner == player_target {
+                        true => self.damage_player(PlayerTarget::Player, damage),
+                        false => self.damage_player(PlayerTarget::Opponent, damage),
                     };
-                    match owner == player_target {
-                        true => self.player.take_damage(damage),
-                        false => self.opponent.take_damage(damage),
+                    // Damage-linked effects see the damage after crit and mitigation but before
+                    // shield absorption
+                    let dealt = record.pre_mitigation - record.mitigated;
+                    follow_up_events.extend(
+                        linked
+                            .iter()
+                            .map(|e| TaggedCombatEvent(*owner, e.clone().with_damage_dealt(dealt))),
+                    );
+                    if let Some(card) = self.cards.get(source_id) {
+                        if card.has_lifesteal() && dealt > 0 {
+                            follow_up_events.push(TaggedCombatEvent(
+                                *owner,
+                                CombatEvent::Lifesteal(PlayerTarget::Player, dealt, *source_id),
+                            ));
+                        }
                     }
                 } else {
-                    let todo = true; //TODO else what?
+                    self.dispatch_event(&DispatchableEvent::Warning(format!(
+                        "attempted to deal damage from card with id {source_id} which isn't on the board"
+                    )));
                 }
             }
             TaggedCombatEvent(
                 owner,
                 CombatEvent::ApplyBurn(player_target, burn_derivable, source_id),
             ) => {
-                let todo = true; //TODO burn crit
-                let burn = match burn_derivable {
-                    DerivedValue::Constant(b) => *b,
-                    _ => self.derive_value(burn_derivable.clone(), source_id)? as u32,
-                };
+                let burn = self.resolve_effect_value(burn_derivable, source_id)?;
                 match owner == player_target {
                     true => self.player.burn(burn),
                     false => self.opponent.burn(burn),
@@ -200,11 +826,7 @@ impl Simulation {
                 owner,
                 CombatEvent::ApplyPoison(player_target, poison_derivable, source_id),
             ) => {
-                let todo = true; //TODO poison crit
-                let poison = match poison_derivable {
-                    DerivedValue::Constant(p) => *p,
-                    _ => self.derive_value(poison_derivable.clone(), source_id)? as u32,
-                };
+                let poison = self.resolve_effect_value(poison_derivable, source_id)?;
                 match owner == player_target {
                     true => self.player.poison(poison),
                     false => self.opponent.poison(poison),
@@ -214,63 +836,48 @@ impl Simulation {
                 owner,
                 CombatEvent::ApplyShield(player_target, shield, source_id),
             ) => {
-                let todo = true; //TODO shield crit
-                let shield_value = match *shield {
-                    DerivedValue::Constant(s) => s,
-                    _ => self.derive_value(shield.clone(), source_id)? as u32,
-                };
+                let shield_value = self.resolve_effect_value(shield, source_id)?;
                 match owner == player_target {
                     true => self.player.shield(shield_value),
                     false => self.opponent.shield(shield_value),
                 }
             }
             TaggedCombatEvent(owner, CombatEvent::Regen(player_target, regen, source_id)) => {
-                let todo = true; //TODO regen crit
-                let regen_value = match *regen {
-                    DerivedValue::Constant(s) => s,
-                    _ => self.derive_value(regen.clone(), source_id)? as u32,
-                };
+                let regen_value = self.resolve_effect_value(regen, source_id)?;
                 match owner == player_target {
                     true => self.player.regen(regen_value),
                     false => self.opponent.regen(regen_value),
                 }
             }
-            TaggedCombatEvent(owner, CombatEvent::Heal(player_target, heal, source_id)) => {
-                let todo = true; //TODO heal crit
-                let heal_value = match *heal {
-                    DerivedValue::Constant(s) => s,
-                    _ => self.derive_value(heal.clone(), source_id)? as u32,
+            TaggedCombatEvent(owner, CombatEvent::DamageImmunity(player_target, duration, ..)) => {
+                let (target, player) = match owner == player_target {
+                    true => (PlayerTarget::Player, &mut self.player),
+                    false => (PlayerTarget::Opponent, &mut self.opponent),
                 };
+                player.grant_immunity(*duration);
+                self.dispatch_event(&DispatchableEvent::DamageImmunityStarted(target, *duration));
+            }
+            TaggedCombatEvent(owner, CombatEvent::Heal(player_target, heal, source_id)) => {
+                let heal_value = self.resolve_effect_value(heal, source_id)?;
                 match owner == player_target {
                     true => self.player.heal(heal_value),
                     false => self.opponent.heal(heal_value),
                 }
             }
-            TaggedCombatEvent(.., CombatEvent::Haste(target, duration, source_id)) => {
-                let candidate_ids: Vec<GlobalCardId> = self
-                    .get_cards_by_target(source_id, target.target_condition())
-                    .into_iter()
-                    .collect();
-                let to_haste = target.number_of_targets();
-                self.dispatch_log(format!("Haste request: {}", to_haste));
-
-                let (mut no_haste, mut has_haste): (Vec<_>, Vec<_>) =
-                    candidate_ids.into_iter().partition(|&id| {
-                        self.cards
-                            .get(&id)
-                            .map_or(false, |card| card.haste_ticks == GameTicks(0))
-                    });
-
-                no_haste.shuffle(rng);
-                has_haste.shuffle(rng);
-
-                let mut chosen = Vec::new();
-                chosen.extend(no_haste.into_iter().take(to_haste));
-                if chosen.len() < to_haste {
-                    chosen.extend(has_haste.into_iter().take(to_haste - chosen.len()));
+            TaggedCombatEvent(owner, CombatEvent::Lifesteal(player_target, amount, ..)) => {
+                match owner == player_target {
+                    true => self.player.heal(*amount),
+                    false => self.opponent.heal(*amount),
                 }
-
-                self.dispatch_log(format!("Selected to haste: {:?}", chosen));
+            }
+            TaggedCombatEvent(.., CombatEvent::Haste(target, duration, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    Card::has_cooldown,
+                    |card| card.haste_ticks == GameTicks(0),
+                    rng,
+                );
 
                 for id in chosen {
                     if let Some(card_ref) = self.cards.get(&id) {
@@ -287,48 +894,38 @@ impl Simulation {
                     }
                 }
             }
-            TaggedCombatEvent(.., CombatEvent::Slow(target, duration, source_id)) => {
-                let mut candidate_ids: Vec<GlobalCardId> = self
-                    .get_cards_by_target(source_id, target.target_condition())
-                    .into_iter()
-                    .collect();
-                let to_slow = target.number_of_targets();
-                self.dispatch_log(format!("Slow request: {}", to_slow));
-
-                candidate_ids.retain(|&id| {
-                    let todo = true; //TODO optimize
-                    self.cards
-                        .get(&id)
-                        .and_then(|card| {
-                            card.modification_tooltips
-                                .iter()
-                                .find(|m| matches!(m, Tooltip::StaticModifier(Modifier::Radiant)))
-                        })
-                        .is_none()
-                        && self
-                            .cards
-                            .get(&id)
-                            .and_then(|card| Some(card.cooldown > GameTicks(0)))
-                            .is_some()
-                });
-
-                let (mut not_slowed, mut already_slowed): (Vec<_>, Vec<_>) =
-                    candidate_ids.into_iter().partition(|&id| {
-                        self.cards
-                            .get(&id)
-                            .map_or(false, |card| card.slow_ticks == GameTicks(0))
-                    });
-
-                not_slowed.shuffle(rng);
-                already_slowed.shuffle(rng);
-
-                let mut chosen = Vec::new();
-                chosen.extend(not_slowed.into_iter().take(to_slow));
-                if chosen.len() < to_slow {
-                    chosen.extend(already_slowed.into_iter().take(to_slow - chosen.len()));
-                }
+            TaggedCombatEvent(.., CombatEvent::Charge(target, duration, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    Card::has_cooldown,
+                    |card| card.freeze_ticks == GameTicks(0),
+                    rng,
+                );
 
-                self.dispatch_log(format!("Selected card to slow: {:?}", chosen));
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        let owner = card_mut.owner;
+                        let events = card_mut.charge(*duration);
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardCharged(summary, *duration));
+                        follow_up_events
+                            .extend(events.into_iter().map(|e| TaggedCombatEvent(owner, e)));
+                    } else {
+                        self.dispatch_event(&DispatchableEvent::Warning(format!(
+                            "attempted to charge card with id {id} which isn't on the board"
+                        )));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::Slow(target, duration, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| card.has_cooldown() && !card.is_radiant(),
+                    |card| card.slow_ticks == GameTicks(0),
+                    rng,
+                );
 
                 for id in chosen {
                     if let Some(card_ref) = self.cards.get(&id) {
@@ -348,47 +945,13 @@ impl Simulation {
                 }
             }
             TaggedCombatEvent(.., CombatEvent::Freeze(target, duration, source_id)) => {
-                let mut candidate_ids: Vec<GlobalCardId> = self
-                    .get_cards_by_target(source_id, target.target_condition())
-                    .into_iter()
-                    .collect();
-                let to_freeze = target.number_of_targets();
-                self.dispatch_log(format!("Freeze request: {}", to_freeze));
-
-                candidate_ids.retain(|&id| {
-                    let todo = true; //TODO optimize
-                    self.cards
-                        .get(&id)
-                        .and_then(|card| {
-                            card.modification_tooltips
-                                .iter()
-                                .find(|m| matches!(m, Tooltip::StaticModifier(Modifier::Radiant)))
-                        })
-                        .is_none()
-                        && self
-                            .cards
-                            .get(&id)
-                            .and_then(|card| Some(card.cooldown > GameTicks(0)))
-                            .is_some()
-                });
-
-                let (mut not_frozen, mut already_frozen): (Vec<_>, Vec<_>) =
-                    candidate_ids.into_iter().partition(|&id| {
-                        self.cards
-                            .get(&id)
-                            .map_or(false, |card| card.freeze_ticks == GameTicks(0))
-                    });
-
-                not_frozen.shuffle(rng);
-                already_frozen.shuffle(rng);
-
-                let mut chosen = Vec::new();
-                chosen.extend(not_frozen.into_iter().take(to_freeze));
-                if chosen.len() < to_freeze {
-                    chosen.extend(already_frozen.into_iter().take(to_freeze - chosen.len()));
-                }
-
-                self.dispatch_log(format!("Selected card to freeze: {:?}", chosen));
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| card.has_cooldown() && !card.is_radiant(),
+                    |card| card.freeze_ticks == GameTicks(0),
+                    rng,
+                );
 
                 for id in chosen {
                     if let Some(card_ref) = self.cards.get(&id) {
@@ -405,6 +968,188 @@ impl Simulation {
                     }
                 }
             }
+            TaggedCombatEvent(.., CombatEvent::Reload(target, amount, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| card.max_ammo.is_some(),
+                    |card| Some(card.ammo) != card.max_ammo,
+                    rng,
+                );
+
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        let restored = card_mut.reload(*amount);
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardReloaded(summary, restored));
+                    } else {
+                        self.dispatch_event(&DispatchableEvent::Warning(format!(
+                            "attempted to reload card with id {id} which isn't on the board"
+                        )));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::IncreaseMaxAmmo(target, amount, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| card.max_ammo.is_some(),
+                    |_| true,
+                    rng,
+                );
+
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        let max_ammo = card_mut.max_ammo.unwrap_or(0);
+                        let increase = match amount {
+                            EffectValue::Flat(a) => *a,
+                            EffectValue::Percentage(p) => max_ammo * p / 100,
+                        };
+                        card_mut.increase_max_ammo(increase);
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardMaxAmmoIncreased(
+                            summary, increase,
+                        ));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::UseCard(target, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| {
+                        !card.cooldown_effects.is_empty() && !card.is_frozen() && card.has_ammo()
+                    },
+                    |_| true,
+                    rng,
+                );
+
+                for id in chosen {
+                    // A use counts as a trigger of the used card, which ends use chains
+                    let count = self.triggers_this_tick.entry(id).or_default();
+                    if *count >= *MAX_TRIGGERS_PER_CARD_PER_TICK {
+                        self.dispatch_event(&DispatchableEvent::Warning(format!(
+                            "card {id} reached the trigger limit for this tick"
+                        )));
+                        continue;
+                    }
+                    *count += 1;
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        let owner = card_mut.owner;
+                        follow_up_events.extend(
+                            card_mut
+                                .use_card()
+                                .into_iter()
+                                .map(|e| TaggedCombatEvent(owner, e)),
+                        );
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::Destroy(target, source_id)) => {
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| !card.is_radiant(),
+                    |_| true,
+                    rng,
+                );
+
+                for id in &chosen {
+                    self.destroy_card(id);
+                }
+                if !chosen.is_empty() {
+                    // Destroyed cards no longer count towards conditions or as neighbours
+                    self.update_conditional_tooltips();
+                }
+            }
+            TaggedCombatEvent(owner, CombatEvent::TransformIntoCopy(target, tier, source_id)) => {
+                // A copy takes over the board span of the item, so only items of the same size
+                // can be copied without overlapping the neighbours
+                let size = self.cards.get(source_id).map(|card| card.inner.size);
+                let chosen = self.select_targets(
+                    source_id,
+                    target,
+                    |card| Some(card.inner.size) == size,
+                    |_| true,
+                    rng,
+                );
+
+                if let Some(inner) = chosen
+                    .first()
+                    .and_then(|id| self.cards.get(id))
+                    .map(|card| card.inner.clone())
+                {
+                    let triggered = self.trigger_card_transformed(source_id, rng);
+                    let tags = self.cards[source_id].inner.tags.clone();
+                    // Copies of items that start above the requested tier keep their own tier
+                    let tier = (*tier).max(inner.min_tier());
+                    let name = inner.name;
+                    self.transform_card(source_id, inner, tier)?;
+                    if let Some(card) = self.cards.get(source_id) {
+                        let summary = CardSummary::from(card);
+                        self.dispatch_event(&DispatchableEvent::CardTransformed(summary));
+                    }
+                    follow_up_events.push(TaggedCombatEvent(
+                        *owner,
+                        CombatEvent::Transformed(*source_id, name, tier, tags),
+                    ));
+                    follow_up_events.extend(triggered);
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::Transformed(..)) => {}
+            TaggedCombatEvent(.., CombatEvent::CooldownReduction(target, reduction, source_id)) => {
+                let chosen =
+                    self.select_targets(source_id, target, Card::has_cooldown, |_| true, rng);
+
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        card_mut.reduce_cooldown(reduction);
+                        let cooldown = card_mut.cooldown;
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardCooldownChanged(
+                            summary, cooldown,
+                        ));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::IncreaseDamage(target, amount, source_id)) => {
+                let chosen = self.select_targets(source_id, target, |_| true, |_| true, rng);
+
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        card_mut.stats.increase_damage(amount);
+                        let damage = card_mut.stats.effective_damage();
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardDamageIncreased(
+                            summary, damage,
+                        ));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::GainShield(target, amount, source_id)) => {
+                let chosen = self.select_targets(source_id, target, |_| true, |_| true, rng);
+
+                for id in chosen {
+                    if let Some(card_mut) = self.cards.get_mut(&id) {
+                        card_mut.stats.increase_shield(amount);
+                        let shield = card_mut.stats.shield;
+                        let summary = CardSummary::from(card_mut);
+                        self.dispatch_event(&DispatchableEvent::CardShieldIncreased(
+                            summary, shield,
+                        ));
+                    }
+                }
+            }
+            TaggedCombatEvent(.., CombatEvent::Sandstorm(damage)) => {
+                self.dispatch_event(&DispatchableEvent::Sandstorm(*damage));
+                for target in [PlayerTarget::Player, PlayerTarget::Opponent] {
+                    let record = match target {
+                        PlayerTarget::Player => self.player.take_sandstorm_damage(*damage),
+                        PlayerTarget::Opponent => self.opponent.take_sandstorm_damage(*damage),
+                    };
+                    self.dispatch_event(&DispatchableEvent::DamageTaken(target, record));
+                }
+            }
             TaggedCombatEvent(.., CombatEvent::Tick(..)) => {}
             // Keeping this is useful whenever new events are implemented
             #[allow(unreachable_patterns)]
@@ -412,7 +1157,8 @@ impl Simulation {
                 "Unable to apply event: {event:?}"
             ))),
         }
-        Ok(())
+        follow_up_events.extend(self.trigger_action(event, rng));
+        Ok(follow_up_events)
     }
 
     pub fn derive_value(
@@ -420,7 +1166,6 @@ impl Simulation {
         value: DerivedValue<u32>,
         source_id: &GlobalCardId,
     ) -> anyhow::Result<f32> {
-        let todo = true; //TODO probably wont scale long term with just u32
         let v = value.clone();
         match value {
             DerivedValue::Constant(..) => anyhow::bail!("constants do not need to be derived"),
@@ -428,36 +1173,64 @@ impl Simulation {
                 let targets: Vec<&Card> = self
                     .get_cards_by_target(source_id, card_target.target_condition())
                     .iter()
-                    .map(|id| self.cards.get(id))
-                    .flatten()
+                    .filter_map(|id| self.cards.get(id))
                     .collect();
-                let todo = true; //TODO: Use the modifications as well
+                // Card stats already include the card's modifications
                 match card_derived_property {
-                    CardDerivedProperty::Value => Ok(modifier
-                        * targets
-                            .iter()
-                            .map(|t| t.tier.scale_cost(t.inner.size.base_cost()) as f32)
-                            .sum::<f32>()),
-                    CardDerivedProperty::Damage => Ok(modifier
-                        * targets
-                            .iter()
-                            .flat_map(|t| {
-                                t.cooldown_effects.iter().map(|e| match e {
-                                    Effect::DealDamage(.., d) => match d {
-                                        DerivedValue::Constant(v) => Some(*v as f32),
-                                        v => {
-                                            // SAFETY Careful with recursion
-                                            self.derive_value(v.clone(), source_id).ok()
-                                        }
-                                    },
-                                    _ => None,
-                                })
-                            })
-                            .flatten()
-                            .sum::<f32>()),
+                    CardDerivedProperty::Value => {
+                        Ok(modifier * targets.iter().map(|t| t.stats.value as f32).sum::<f32>())
+                    }
+                    CardDerivedProperty::Damage => {
+                        let mut damage = 0.0;
+                        for target in &targets {
+                            for effect in &target.cooldown_effects {
+                                let Effect::DealDamage(.., d) = effect else {
+                                    continue;
+                                };
+                                damage += match d {
+                                    DerivedValue::Constant(c) => {
+                                        target.stats.effective_damage_of(*c) as f32
+                                    }
+                                    // Damage derived from damage could go back and forth forever
+                                    DerivedValue::FromCard(_, CardDerivedProperty::Damage, _) => {
+                                        anyhow::bail!(
+                                            "damage of card {} is derived from damage",
+                                            target.id_for_simulation
+                                        )
+                                    }
+                                    d => self.derive_value(d.clone(), &target.id_for_simulation)?,
+                                };
+                            }
+                        }
+                        Ok(modifier * damage)
+                    }
+                    CardDerivedProperty::Count => Ok(modifier * targets.len() as f32),
                 }
             }
-            DerivedValue::FromPlayer(..) => todo!(),
+            DerivedValue::FromPlayer(card_target, player_derived_property, modifier) => {
+                let Some(source) = self.cards.get(source_id) else {
+                    anyhow::bail!("unknown source card {source_id}")
+                };
+                let player = match card_target.target_condition() {
+                    TargetCondition::HasOwner(player_target) => {
+                        match source.owner == *player_target {
+                            true => &self.player,
+                            false => &self.opponent,
+                        }
+                    }
+                    condition => anyhow::bail!("unable to derive a player from {condition:?}"),
+                };
+                let property = match player_derived_property {
+                    PlayerDerivedProperty::MaximumHealth => player.health.max() as f32,
+                    PlayerDerivedProperty::CurrentHealth => player.health.current().max(0) as f32,
+                    PlayerDerivedProperty::Shield => player.shield_stacks.max(0) as f32,
+                    PlayerDerivedProperty::Burn => player.burn_stacks.max(0) as f32,
+                    PlayerDerivedProperty::Poison => player.poison_stacks.max(0) as f32,
+                    PlayerDerivedProperty::Regen => player.regeneration_stacks.max(0) as f32,
+                    PlayerDerivedProperty::Gold => player.gold as f32,
+                };
+                Ok(modifier * property)
+            }
         }
         .inspect(|derived| self.dispatch_log(format!("Derived {derived:?} from {v:?}")))
     }
@@ -471,13 +1244,20 @@ impl Simulation {
         let player_dead = self.player.health.current() <= 0;
         let opponent_dead = self.opponent.health.current() <= 0;
         if player_dead && opponent_dead {
+            let draw_type = match self.fought_in_sandstorm() {
+                true => SimulationDrawType::Sandstorm,
+                false => SimulationDrawType::SimultaneousDefeat,
+            };
             return Some(SimulationResult::Draw(
-                SimulationDrawType::SimultaneousDefeat,
+                draw_type,
                 SimulationResultInner {
                     events: events.clone(),
                     duration: t_now - t_start,
                     player: self.player.clone(),
                     opponent: self.opponent.clone(),
+                    sandstorm: self.fought_in_sandstorm(),
+                    cards: self.card_summaries(),
+                    skills: self.skill_summaries(),
                 },
             ));
         }
@@ -487,6 +1267,9 @@ impl Simulation {
                 duration: t_now - t_start,
                 player: self.player.clone(),
                 opponent: self.opponent.clone(),
+                sandstorm: self.fought_in_sandstorm(),
+                cards: self.card_summaries(),
+                skills: self.skill_summaries(),
             }));
         }
         if self.player.health.current() <= 0 {
@@ -495,28 +1278,139 @@ impl Simulation {
                 duration: t_now - t_start,
                 player: self.player.clone(),
                 opponent: self.opponent.clone(),
+                sandstorm: self.fought_in_sandstorm(),
+                cards: self.card_summaries(),
+                skills: self.skill_summaries(),
             }));
         }
         None
     }
 
+    /// Applies `initial_events` and everything they cause, logging every applied event.
+    fn resolve_events(
+        &mut self,
+        initial_events: Vec<TaggedCombatEvent>,
+        rng: &mut StdRng,
+        log: &mut Vec<TaggedCombatEvent>,
+    ) {
+        let mut pending_events: VecDeque<TaggedCombatEvent> = initial_events.into();
+        while let Some(event) = pending_events.pop_front() {
+            let follow_up_events = self
+                .apply_event(&event, rng)
+                .inspect_err(|error| {
+                    self.dispatch_event(&DispatchableEvent::Error(format!("{error}")))
+                })
+                .unwrap_or_default();
+            pending_events.extend(follow_up_events);
+            pending_events.extend(self.trigger_first_time(rng));
+            log.push(event);
+        }
+    }
+
+    /// Effects of every card that happen at the start of the fight, in board order.
+    pub fn fight_start_events(&self) -> Vec<TaggedCombatEvent> {
+        let mut events = Vec::new();
+        for card in self.cards.values() {
+            for tooltip in &card.tooltips {
+                let Tooltip::When(EffectEvent::OnFightStart(effect)) = tooltip else {
+                    continue;
+                };
+                events.extend(
+                    card.effect_to_combat_events(effect.clone())
+                        .into_iter()
+                        .map(|e| TaggedCombatEvent(card.owner, e)),
+                );
+            }
+        }
+        events
+    }
+
+    /// Sums the `LessDamageTaken` modifiers on the cards of `owner`.
+    pub fn damage_mitigation(&self, owner: PlayerTarget) -> DamageMitigation {
+        self.cards
+            .values()
+            .filter(|card| card.owner == owner && !card.destroyed)
+            .flat_map(|card| card.tooltips.iter())
+            .fold(DamageMitigation::default(), |mut mitigation, t| {
+                match t {
+                    Tooltip::StaticModifier(Modifier::LessDamageTaken(
+                        EffectValue::Percentage(p),
+                    )) => {
+                        mitigation.percentage = Percentage::from_fraction(
+                            mitigation.percentage.as_fraction()
+                                + Percentage::from_percentage_value(*p as f64).as_fraction(),
+                        )
+                    }
+                    Tooltip::StaticModifier(Modifier::LessDamageTaken(EffectValue::Flat(f))) => {
+                        mitigation.flat += f
+                    }
+                    _ => {}
+                }
+                mitigation
+            })
+    }
+
+    /// Runs direct damage through the damage pipeline of `target` and announces the outcome.
+    fn damage_player(&mut self, target: PlayerTarget, amount: u32) -> DamageRecord {
+        let mitigation = self.damage_mitigation(target);
+        let record = match target {
+            PlayerTarget::Player => self.player.take_damage(amount, mitigation),
+            PlayerTarget::Opponent => self.opponent.take_damage(amount, mitigation),
+        };
+        self.dispatch_event(&DispatchableEvent::DamageTaken(target, record));
+        record
+    }
+
+    /// Whether `condition` holds on the board of `owner`. Conditions that depend on the card
+    /// holding the tooltip never hold here, see `card_condition_holds`.
+    pub fn condition_holds(&self, owner: PlayerTarget, condition: &Condition) -> bool {
+        let mut cards = self
+            .get_cards_by_owner(owner)
+            .iter()
+            .filter_map(|id| self.cards.get(id))
+            .filter(|card| !card.destroyed);
+        match condition {
+            Condition::HasCardOfTag(tag) => cards.any(|card| card.inner.tags.contains(tag)),
+            Condition::HasCardOfSize(size) => cards.any(|card| card.inner.size == *size),
+            Condition::HasExactlyOneCardOfTag(tag) => {
+                cards.filter(|card| card.inner.tags.contains(tag)).count() == 1
+            }
+            Condition::AdjacentCardsHaveTag(..) => false,
+        }
+    }
+
+    /// Whether `condition` holds for the card `id` on its owner's board.
+    pub fn card_condition_holds(&self, id: &GlobalCardId, condition: &Condition) -> bool {
+        let Some(card) = self.cards.get(id) else {
+            return false;
+        };
+        match condition {
+            Condition::AdjacentCardsHaveTag(tag) => {
+                let neighbours = self.get_cards_by_target(id, &TargetCondition::Adjacent);
+                neighbours.len() == 2
+                    && neighbours
+                        .iter()
+                        .filter_map(|id| self.cards.get(id))
+                        .all(|neighbour| neighbour.inner.tags.contains(tag))
+            }
+            condition => self.condition_holds(card.owner, condition),
+        }
+    }
+
     pub fn run_once_with_rng(&mut self, mut rng: StdRng) -> SimulationResult {
         let t_start = Instant::now();
         let mut events = Vec::with_capacity(*SIMULATION_TICK_COUNT);
+        if self.ticks == 0 {
+            let fight_start_events = self.fight_start_events();
+            self.resolve_events(fight_start_events, &mut rng, &mut events);
+        }
         for _ in 0..*SIMULATION_TICK_COUNT {
             if let Some(result) = self.get_exit_condition(Instant::now(), t_start, &events) {
                 return result;
             }
 
             let tick_events = self.tick();
-            for event in &tick_events {
-                self.apply_event(event, &mut rng)
-                    .inspect_err(|error| {
-                        self.dispatch_event(&DispatchableEvent::Error(format!("{error}")))
-                    })
-                    .ok();
-            }
-            events.extend(tick_events);
+            self.resolve_events(tick_events, &mut rng, &mut events);
         }
 
         SimulationResult::Draw(
@@ -526,6 +1420,9 @@ impl Simulation {
                 duration: Instant::now() - t_start,
                 player: self.player.clone(),
                 opponent: self.opponent.clone(),
+                sandstorm: self.fought_in_sandstorm(),
+                cards: self.card_summaries(),
+                skills: self.skill_summaries(),
             },
         )
     }
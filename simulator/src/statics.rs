@@ -6,6 +6,9 @@ lazy_static::lazy_static! {
     pub static ref TICKRATE: f32 = 1000.0 / *TICKS_PER_SECOND as f32;
     pub static ref TICK_DURATION: Duration = Duration::from_millis(TICKRATE.round() as u64);
     pub static ref DURATION_BEFORE_SANDSTORM: Duration = Duration::from_secs(35);
+    pub static ref MAX_TRIGGERS_PER_CARD_PER_TICK: usize = 16;
+    pub static ref CRIT_DAMAGE_MULTIPLIER: u32 = 2;
+    pub static ref MIN_COOLDOWN: Duration = Duration::from_secs(1);
     pub static ref MAX_FIGHT_DURATION: Duration = Duration::from_secs(300);
     pub static ref SIMULATION_TICK_COUNT: usize = {
         let fight_ms = MAX_FIGHT_DURATION.as_micros();
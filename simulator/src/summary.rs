@@ -9,6 +9,9 @@ pub struct SimulationSummary {
     pub defeats: usize,
     pub draw_timeout: usize,
     pub draw_simultaneous: usize,
+    pub draw_sandstorm: usize,
+    pub sandstorm_victories: usize,
+    pub sandstorm_defeats: usize,
     pub average_duration: Duration,
     pub average_player_health: f32,
     pub average_opponent_health: f32,
@@ -21,20 +24,34 @@ impl From<&Vec<SimulationResult>> for SimulationSummary {
         let mut defeats = 0;
         let mut draw_timeout = 0;
         let mut draw_simultaneous = 0;
+        let mut draw_sandstorm = 0;
+        let mut sandstorm_victories = 0;
+        let mut sandstorm_defeats = 0;
         let mut sum_duration = Duration::ZERO;
         let mut sum_player_health = 0f64;
         let mut sum_opponent_health = 0f64;
 
         for res in results.iter() {
+            let inner = res.inner_ref();
             match res {
-                SimulationResult::Victory(..) => victories += 1,
-                SimulationResult::Defeat(..) => defeats += 1,
+                SimulationResult::Victory(..) => {
+                    victories += 1;
+                    if inner.sandstorm {
+                        sandstorm_victories += 1;
+                    }
+                }
+                SimulationResult::Defeat(..) => {
+                    defeats += 1;
+                    if inner.sandstorm {
+                        sandstorm_defeats += 1;
+                    }
+                }
                 SimulationResult::Draw(kind, ..) => match kind {
                     SimulationDrawType::Timeout => draw_timeout += 1,
                     SimulationDrawType::SimultaneousDefeat => draw_simultaneous += 1,
+                    SimulationDrawType::Sandstorm => draw_sandstorm += 1,
                 },
             }
-            let inner = res.inner_ref();
             sum_duration += inner.duration;
             sum_player_health += inner.player.health.current() as f64;
             sum_opponent_health += inner.opponent.health.current() as f64;
@@ -64,6 +81,9 @@ impl From<&Vec<SimulationResult>> for SimulationSummary {
             defeats,
             draw_timeout,
             draw_simultaneous,
+            draw_sandstorm,
+            sandstorm_victories,
+            sandstorm_defeats,
             average_duration,
             average_player_health,
             average_opponent_health,
@@ -8,6 +8,8 @@ pub struct SimulationTemplate {
     pub opponent: PlayerTemplate,
     #[serde(default)]
     pub seed: Option<u64>,
+    #[serde(default)]
+    pub sandstorm: SandstormTemplate,
 }
 
 impl SimulationTemplate {
@@ -17,6 +19,31 @@ impl SimulationTemplate {
             player: other.opponent,
             opponent: other.player,
             seed: other.seed,
+            sandstorm: other.sandstorm,
+        }
+    }
+}
+
+/// How hard the sandstorm hits once it has started: `base_damage` on its first strike and
+/// `damage_increment` more on every strike after that, one strike every `interval_ms`.
+///
+/// The game does not publish this curve and it has not been measured yet. The default is an
+/// estimate that ends stalled fights within seconds, not game behaviour.
+#[derive(Clone, Debug, Deserialize, PartialEq, Eq)]
+#[serde(default)]
+pub struct SandstormTemplate {
+    pub interval_ms: u64,
+    pub base_damage: u32,
+    pub damage_increment: u32,
+}
+
+impl Default for SandstormTemplate {
+    fn default() -> Self {
+        // Estimated, see above
+        SandstormTemplate {
+            interval_ms: 200,
+            base_damage: 1,
+            damage_increment: 1,
         }
     }
 }
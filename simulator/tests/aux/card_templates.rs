@@ -1,5 +1,5 @@
-use models::v2_0_0::Tier;
-use simulator::CardTemplate;
+use models::v2_0_0::{PlayerTarget, Tier};
+use simulator::{Card, CardTemplate};
 
 lazy_static::lazy_static! {
     pub static ref FANG_CARD_TEMPLATE: CardTemplate = CardTemplate {
@@ -13,3 +13,20 @@ lazy_static::lazy_static! {
         modifications: vec![],
     };
 }
+
+#[allow(unused)]
+pub fn card_template(name: &str, tier: Tier) -> CardTemplate {
+    CardTemplate {
+        name: name.to_string(),
+        tier,
+        modifications: vec![],
+    }
+}
+
+/// A bronze Revolver on the player's board, outside of any simulation.
+#[allow(unused)]
+pub fn revolver() -> Card {
+    card_template("Revolver", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap()
+}
@@ -0,0 +1,33 @@
+use simulator::{CombatEvent, GlobalCardId, SimulationResult};
+
+/// Id of the first card called `name` in the result.
+#[allow(unused)]
+pub fn card_id(result: &SimulationResult, name: &str) -> GlobalCardId {
+    result
+        .inner_ref()
+        .cards
+        .iter()
+        .find(|card| card.name == name)
+        .unwrap()
+        .id
+}
+
+#[allow(unused)]
+pub fn count_uses(events: &[CombatEvent]) -> usize {
+    events
+        .iter()
+        .filter(|e| matches!(e, CombatEvent::CardUsed(..)))
+        .count()
+}
+
+/// Number of times the first card called `name` was used during the fight.
+#[allow(unused)]
+pub fn count_uses_of(result: &SimulationResult, name: &str) -> usize {
+    let id = card_id(result, name);
+    result
+        .inner_ref()
+        .events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::CardUsed(used) if used == id))
+        .count()
+}
@@ -1,7 +1,13 @@
 pub mod card_templates;
+pub mod events;
+pub mod player_templates;
 pub mod sim;
 
 #[allow(unused)]
 pub use card_templates::*;
 #[allow(unused)]
+pub use events::*;
+#[allow(unused)]
+pub use player_templates::*;
+#[allow(unused)]
 pub use sim::*;
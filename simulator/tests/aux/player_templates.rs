@@ -0,0 +1,13 @@
+use simulator::{CardTemplate, PlayerTemplate};
+
+/// A player with enough health to outlast most fights and no regen.
+#[allow(unused)]
+pub fn player(card_templates: Vec<CardTemplate>) -> PlayerTemplate {
+    PlayerTemplate {
+        health: 1000,
+        regen: 0,
+        card_templates,
+        skill_templates: vec![],
+        gold: 0,
+    }
+}
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use rand::{SeedableRng, rngs::StdRng};
+use rand::{rngs::StdRng, SeedableRng};
 use simulator::{Simulation, SimulationResult, SimulationTemplate};
 
 #[allow(unused)]
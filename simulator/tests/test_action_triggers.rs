@@ -0,0 +1,156 @@
+mod aux;
+
+use aux::{card_template, player, SEED};
+use models::v2_0_0::{
+    Action, CardTarget, DerivedValue, Effect, EffectEvent, PlayerTarget, Tag, TargetCondition,
+    TargetSelection, Tier, Tooltip,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rstest::rstest;
+use simulator::{CombatEvent, GlobalCardId, Simulation, SimulationTemplate, TaggedCombatEvent};
+
+fn haste_this() -> Effect {
+    Effect::Haste(
+        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+        1.0,
+    )
+}
+
+#[rstest]
+#[case(
+    "when you slow, deal 10 damage.",
+    vec![Action::Slow]
+)]
+#[case(
+    "when you heal or gain regen, deal 10 damage.",
+    vec![Action::Heal, Action::Regen]
+)]
+#[case(
+    "when you slow, freeze, burn or poison, deal 10 damage.",
+    vec![Action::Slow, Action::Freeze, Action::Burn, Action::Poison]
+)]
+#[case(
+    "when you haste, slow, freeze, poison, or burn, deal 10 damage.",
+    vec![Action::Haste, Action::Slow, Action::Freeze, Action::Poison, Action::Burn]
+)]
+fn test_action_trigger_tooltip(#[case] tooltip: &str, #[case] expected: Vec<Action>) {
+    assert_eq!(
+        Tooltip::try_from(tooltip).unwrap(),
+        Tooltip::When(EffectEvent::OnAction(
+            expected,
+            Effect::DealDamage(PlayerTarget::Opponent, DerivedValue::Constant(10))
+        ))
+    );
+}
+
+#[rstest]
+fn test_action_verb_is_not_taken_from_the_effect() {
+    let Tooltip::When(EffectEvent::OnAction(actions, effect)) =
+        Tooltip::try_from("when you burn, charge this 1 second(s).").unwrap()
+    else {
+        panic!("not an action trigger");
+    };
+    assert_eq!(actions, vec![Action::Burn]);
+    assert!(matches!(effect, Effect::Charge(..)));
+}
+
+#[rstest]
+fn test_action_triggers_fire_for_own_actions() {
+    let burn = Effect::Burn(PlayerTarget::Opponent, DerivedValue::Constant(1));
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Runic Blade", Tier::Gold),
+        ]),
+        opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let burner: GlobalCardId = simulation.player.card_ids[0];
+    let listener: GlobalCardId = simulation.player.card_ids[1];
+    let enemy_burner: GlobalCardId = simulation.opponent.card_ids[0];
+    for id in [burner, enemy_burner] {
+        let card = simulation.cards.get_mut(&id).unwrap();
+        card.cooldown_effects = vec![burn.clone()];
+    }
+    simulation.cards.get_mut(&listener).unwrap().action_triggers =
+        vec![(vec![Action::Burn], haste_this())];
+
+    let result = simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let events = &result.inner_ref().events;
+    let burns = events
+        .iter()
+        .filter(|e| {
+            matches!(e, TaggedCombatEvent(PlayerTarget::Player, CombatEvent::ApplyBurn(.., id)) if *id == burner)
+        })
+        .count();
+    let hastes = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Haste(.., id) if id == listener))
+        .count();
+    assert!(burns > 0);
+    // The enemy burning does not count as an action of the player
+    assert_eq!(hastes, burns);
+}
+
+#[rstest]
+#[case(
+    "when you reload or transform a potion, this gains +10 damage for the fight.",
+    vec![Action::Reload, Action::Transform(Tag::Potion)]
+)]
+#[case(
+    "when you transform a reagent, this permanently gains +3 burn.",
+    vec![Action::Transform(Tag::Reagent)]
+)]
+fn test_transform_action_tooltip(#[case] tooltip: &str, #[case] expected: Vec<Action>) {
+    let Tooltip::When(EffectEvent::OnAction(actions, _)) = Tooltip::try_from(tooltip).unwrap()
+    else {
+        panic!("not an action trigger");
+    };
+    assert_eq!(actions, expected);
+}
+
+#[rstest]
+fn test_transforming_a_potion_is_an_action() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fire Potion", Tier::Bronze),
+            card_template("Fang", Tier::Bronze),
+            card_template("Runic Blade", Tier::Gold),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let potion: GlobalCardId = simulation.player.card_ids[0];
+    let listener: GlobalCardId = simulation.player.card_ids[2];
+    simulation.cards.get_mut(&potion).unwrap().cooldown_effects = vec![Effect::TransformIntoCopy(
+        CardTarget(1, TargetCondition::Adjacent, TargetSelection::Random),
+        Tier::Bronze,
+    )];
+    let Tooltip::When(EffectEvent::OnAction(actions, effect)) = Tooltip::try_from(
+        "when you reload or transform a potion, this gains +10 damage for the fight.",
+    )
+    .unwrap() else {
+        panic!("not an action trigger");
+    };
+    simulation.cards.get_mut(&listener).unwrap().action_triggers = vec![(actions, effect)];
+
+    let result = simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let events = &result.inner_ref().events;
+    let transformed = events
+        .iter()
+        .position(|e| matches!(e.1, CombatEvent::Transformed(id, "Fang", ..) if id == potion))
+        .unwrap();
+    let gains = |events: &[TaggedCombatEvent]| {
+        events
+            .iter()
+            .filter(|e| matches!(e.1, CombatEvent::IncreaseDamage(.., id) if id == listener))
+            .count()
+    };
+    assert_eq!(gains(&events[..transformed]), 0);
+    // The copy is no longer a potion, only the first transformation counts
+    assert_eq!(gains(&events[transformed..]), 1);
+}
@@ -0,0 +1,90 @@
+mod aux;
+
+use aux::{card_template, count_uses, player, revolver, run_simulation};
+use models::v2_0_0::{CardTarget, Effect, TargetCondition, TargetSelection, Tier};
+use rstest::rstest;
+use simulator::SimulationTemplate;
+
+#[rstest]
+fn test_card_starts_with_full_ammo() {
+    let card = revolver();
+    assert_eq!(card.max_ammo, Some(6));
+    assert_eq!(card.ammo, 6);
+}
+
+#[rstest]
+fn test_card_without_ammo_cannot_be_used() {
+    let mut card = revolver();
+    for _ in 0..6 {
+        assert_eq!(count_uses(&card.use_card()), 1);
+    }
+    assert_eq!(card.ammo, 0);
+    assert!(card.use_card().is_empty());
+}
+
+#[rstest]
+fn test_reload_is_capped_at_max_ammo() {
+    let mut card = revolver();
+    card.use_card();
+    card.use_card();
+    card.use_card();
+    assert_eq!(card.reload(Some(2)), 2);
+    assert_eq!(card.ammo, 5);
+    assert_eq!(card.reload(Some(2)), 1);
+    assert_eq!(card.ammo, 6);
+}
+
+#[rstest]
+fn test_reload_to_full_reports_ammo_restored() {
+    let mut card = revolver();
+    card.use_card();
+    card.use_card();
+    assert_eq!(card.reload(None), 2);
+    assert_eq!(card.ammo, 6);
+    assert_eq!(card.reload(None), 0);
+}
+
+#[rstest]
+fn test_increase_max_ammo() {
+    let mut card = revolver();
+    card.increase_max_ammo(2);
+    assert_eq!(card.max_ammo, Some(8));
+    assert_eq!(card.ammo, 8);
+}
+
+#[rstest]
+fn test_reload_this_tooltip() {
+    assert_eq!(
+        Effect::from_tooltip_str("reload this 2 ammo."),
+        Effect::Reload(
+            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+            Some(2)
+        )
+    );
+    assert_eq!(
+        Effect::from_tooltip_str("reload this."),
+        Effect::Reload(
+            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+            None
+        )
+    );
+}
+
+#[rstest]
+fn test_card_runs_out_of_ammo_in_combat() -> Result<(), Box<dyn std::error::Error>> {
+    let template = SimulationTemplate {
+        player: player(vec![card_template("Revolver", Tier::Bronze)]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    };
+    let result = run_simulation("revolver".to_string(), template)?;
+    let revolver = result
+        .inner_ref()
+        .cards
+        .iter()
+        .find(|card| card.name == "Revolver")
+        .unwrap();
+    assert_eq!(revolver.ammo, 0);
+    Ok(())
+}
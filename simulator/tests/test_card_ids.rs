@@ -17,14 +17,17 @@ fn simulation_assigns_card_ids_to_correct_players() {
             regen: 0,
             card_templates: vec![FANG_CARD_TEMPLATE.clone()],
             skill_templates: vec![],
+            gold: 0,
         },
         opponent: PlayerTemplate {
             health: 20,
             regen: 0,
             card_templates: vec![FANG_CARD_TEMPLATE.clone()],
             skill_templates: vec![],
+            gold: 0,
         },
         seed: None,
+        sandstorm: Default::default(),
     };
 
     let sim: Simulation = template.try_into().expect("simulation should build");
@@ -0,0 +1,114 @@
+mod aux;
+
+use aux::{card_template, count_uses, count_uses_of, player, revolver, run_simulation};
+use models::v2_0_0::{
+    CardTarget, Effect, PlayerTarget, Tag, TargetCondition, TargetSelection, Tier,
+};
+use rstest::rstest;
+use simulator::{CombatEvent, GameTicks, SimulationTemplate};
+use std::time::Duration;
+
+#[rstest]
+#[case(
+    "charge this 2 second(s).",
+    Effect::Charge(CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random), 2.0)
+)]
+#[case(
+    "charge adjacent items 1 second(s).",
+    Effect::Charge(
+        CardTarget(usize::MAX, TargetCondition::Adjacent, TargetSelection::Random),
+        1.0
+    )
+)]
+#[case(
+    "charge 1 weapon 1 second(s).",
+    Effect::Charge(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(Tag::Weapon), TargetSelection::Random),
+        1.0
+    )
+)]
+#[case(
+    "charge 1 item(s) 1 second(s).",
+    Effect::Charge(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player),
+            TargetSelection::Random
+        ),
+        1.0
+    )
+)]
+fn test_charge_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+fn test_charge_advances_cooldown() {
+    let mut card = revolver();
+    assert!(card.charge(Duration::from_secs(1).into()).is_empty());
+    assert_eq!(
+        card.cooldown_counter,
+        GameTicks::from(Duration::from_secs(1)).0 * 2
+    );
+}
+
+#[rstest]
+fn test_charge_activates_immediately() {
+    let mut card = revolver();
+    let events = card.charge(Duration::from_secs(4).into());
+    assert_eq!(count_uses(&events), 1);
+    assert_eq!(card.ammo, 5);
+}
+
+#[rstest]
+fn test_charge_completes_at_most_one_cooldown() {
+    let mut card = revolver();
+    let events = card.charge(Duration::from_secs(20).into());
+    assert_eq!(count_uses(&events), 1);
+}
+
+#[rstest]
+fn test_frozen_card_activates_after_thawing() {
+    let mut card = revolver();
+    card.freeze(GameTicks(2));
+    assert!(card.charge(Duration::from_secs(4).into()).is_empty());
+    assert!(card
+        .tick()
+        .iter()
+        .all(|e| matches!(e, CombatEvent::Skip(..))));
+    assert!(card
+        .tick()
+        .iter()
+        .all(|e| matches!(e, CombatEvent::Skip(..))));
+    assert_eq!(count_uses(&card.tick()), 1);
+}
+
+#[rstest]
+fn test_charge_speeds_up_adjacent_items() -> Result<(), Box<dyn std::error::Error>> {
+    let opponent = player(vec![]);
+    let without_charge = run_simulation(
+        "without charge".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Fang", Tier::Bronze)]),
+            opponent: opponent.clone(),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let with_charge = run_simulation(
+        "with charge".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Fang", Tier::Bronze),
+                card_template("Black Pepper", Tier::Diamond),
+            ]),
+            opponent,
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    assert!(count_uses_of(&with_charge, "Fang") > count_uses_of(&without_charge, "Fang"));
+    Ok(())
+}
@@ -13,6 +13,7 @@ fn test_cleanse() -> Result<(), Box<dyn std::error::Error>> {
         regen: 0,
         card_templates: vec![],
         skill_templates: vec![],
+        gold: 0,
     }
     .create_player(vec![])?;
     player.burn_stacks = 100;
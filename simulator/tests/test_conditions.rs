@@ -0,0 +1,189 @@
+mod aux;
+
+use aux::{card_template, player};
+use models::v2_0_0::{
+    Condition, DerivedValue, EffectValue, Modifier, Percentage, PlayerTarget, Size, Tag,
+    TargetCondition, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{
+    CardTemplate, ConditionalTooltip, GameTicks, GlobalCardId, Simulation, SimulationTemplate,
+    TriggerId,
+};
+use std::time::Duration;
+
+fn simulation(card_templates: Vec<CardTemplate>) -> Simulation {
+    Simulation::try_from(SimulationTemplate {
+        player: player(card_templates),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+}
+
+fn fang_cooldowns(simulation: &Simulation) -> Vec<GameTicks> {
+    simulation
+        .cards
+        .values()
+        .filter(|card| card.inner.name == "Fang")
+        .map(|card| card.cooldown)
+        .collect()
+}
+
+#[rstest]
+#[case(
+    "if both adjacent items are food, this has +1 multicast.",
+    Tooltip::ConditionalModifier(
+        Condition::AdjacentCardsHaveTag(Tag::Food),
+        Modifier::IncreasedMulticast(DerivedValue::Constant(1))
+    )
+)]
+#[case(
+    "this has +1 multicast if you have a large item.",
+    Tooltip::ConditionalModifier(
+        Condition::HasCardOfSize(Size::Large),
+        Modifier::IncreasedMulticast(DerivedValue::Constant(1))
+    )
+)]
+#[case(
+    "if you have exactly one weapon, reduce its cooldown by 20%.",
+    Tooltip::ConditionalModifier(
+        Condition::HasExactlyOneCardOfTag(Tag::Weapon),
+        Modifier::GrantCooldownReduction(
+            TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(Tag::Weapon),
+            Percentage::from_percentage_value(20.0)
+        )
+    )
+)]
+fn test_conditional_modifier_tooltip(#[case] tooltip: &str, #[case] expected: Tooltip) {
+    assert_eq!(Tooltip::try_from(tooltip).unwrap(), expected);
+}
+
+#[rstest]
+#[case(vec![card_template("Silencer", Tier::Bronze), card_template("Fang", Tier::Bronze)], vec![2.55])]
+#[case(
+    vec![
+        card_template("Silencer", Tier::Bronze),
+        card_template("Fang", Tier::Bronze),
+        card_template("Fang", Tier::Bronze),
+    ],
+    vec![3.0, 3.0]
+)]
+fn test_exactly_one_weapon(#[case] cards: Vec<CardTemplate>, #[case] expected: Vec<f64>) {
+    let expected: Vec<GameTicks> = expected
+        .into_iter()
+        .map(|s| Duration::from_secs_f64(s).into())
+        .collect();
+    assert_eq!(fang_cooldowns(&simulation(cards)), expected);
+}
+
+#[rstest]
+fn test_conditions_reevaluated_when_board_changes() {
+    let mut simulation = simulation(vec![
+        card_template("Black Pepper", Tier::Silver),
+        card_template("Hot Sauce", Tier::Gold),
+        card_template("Black Pepper", Tier::Silver),
+    ]);
+    let pepper: GlobalCardId = simulation.player.card_ids[0];
+    let hot_sauce: GlobalCardId = simulation.player.card_ids[1];
+    let multicast = |simulation: &Simulation| simulation.cards.get(&hot_sauce).unwrap().multicast;
+    let base_multicast = multicast(&simulation);
+
+    simulation
+        .cards
+        .get_mut(&hot_sauce)
+        .unwrap()
+        .conditional_tooltips
+        .push(ConditionalTooltip {
+            condition: Condition::AdjacentCardsHaveTag(Tag::Food),
+            tooltip: Tooltip::StaticModifier(Modifier::IncreasedMulticast(DerivedValue::Constant(
+                1,
+            ))),
+            active: false,
+            trigger_id: TriggerId::new(),
+        });
+    simulation.update_conditional_tooltips();
+    assert_eq!(multicast(&simulation), base_multicast + 1);
+
+    simulation.cards.get_mut(&pepper).unwrap().destroy();
+    simulation.update_conditional_tooltips();
+    // Losing a neighbour drops the conditional bonus and one of the adjacency bonuses
+    assert_eq!(multicast(&simulation), base_multicast - 1);
+    assert!(
+        !simulation
+            .cards
+            .get(&hot_sauce)
+            .unwrap()
+            .conditional_tooltips[0]
+            .active
+    );
+}
+
+#[rstest]
+fn test_conditional_weapon_damage_follows_its_condition() {
+    let mut simulation = simulation(vec![
+        card_template("Fang", Tier::Bronze),
+        card_template("Bar of Gold", Tier::Bronze),
+        card_template("Bar of Gold", Tier::Bronze),
+    ]);
+    let [fang, source, other] = simulation.player.card_ids[..] else {
+        panic!("expected three cards");
+    };
+    simulation
+        .cards
+        .get_mut(&source)
+        .unwrap()
+        .conditional_tooltips
+        .push(ConditionalTooltip {
+            condition: Condition::HasCardOfTag(Tag::Tool),
+            tooltip: Tooltip::StaticModifier(Modifier::WeaponDamage(EffectValue::Flat(6))),
+            active: false,
+            trigger_id: TriggerId::new(),
+        });
+    simulation.update_conditional_tooltips();
+    let damage = |simulation: &Simulation, id| simulation.cards[&id].stats.damage;
+    assert_eq!(damage(&simulation, fang), 5);
+
+    let hammer = card_template("Hammer", Tier::Silver)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    simulation
+        .transform_card(&other, hammer.inner, Tier::Silver)
+        .unwrap();
+    assert_eq!(damage(&simulation, fang), 5 + 6);
+    assert_eq!(damage(&simulation, other), hammer.stats.damage + 6);
+
+    simulation.destroy_card(&other);
+    simulation.update_conditional_tooltips();
+    assert_eq!(damage(&simulation, fang), 5);
+}
+
+#[rstest]
+fn test_conditional_cooldown_reduction_follows_its_condition() {
+    let mut simulation = simulation(vec![
+        card_template("Silencer", Tier::Bronze),
+        card_template("Fang", Tier::Bronze),
+        card_template("Bar of Gold", Tier::Bronze),
+    ]);
+    let [_, fang, other] = simulation.player.card_ids[..] else {
+        panic!("expected three cards");
+    };
+    let cooldown = |simulation: &Simulation| simulation.cards[&fang].cooldown;
+    let reduced: GameTicks = Duration::from_secs_f64(2.55).into();
+    let base: GameTicks = Duration::from_secs_f64(3.0).into();
+    assert_eq!(cooldown(&simulation), reduced);
+
+    let second_fang = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    simulation
+        .transform_card(&other, second_fang.inner, Tier::Bronze)
+        .unwrap();
+    assert_eq!(cooldown(&simulation), base);
+    assert_eq!(simulation.cards[&other].cooldown, base);
+
+    simulation.destroy_card(&other);
+    simulation.update_conditional_tooltips();
+    assert_eq!(cooldown(&simulation), reduced);
+}
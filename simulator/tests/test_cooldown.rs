@@ -0,0 +1,135 @@
+mod aux;
+
+use aux::{card_template, player};
+use models::v2_0_0::{
+    CardTarget, Effect, EffectValue, Modifier, Percentage, PlayerTarget, TargetCondition,
+    TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{Card, CombatEvent, GameTicks, Simulation, SimulationTemplate, MIN_COOLDOWN};
+use std::time::Duration;
+
+fn fang() -> Card {
+    card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap()
+}
+
+fn seconds(s: f64) -> GameTicks {
+    Duration::from_secs_f64(s).into()
+}
+
+#[rstest]
+#[case(
+    "reduce this item's cooldown by 1 second(s) for the fight.",
+    Effect::CooldownReduction(
+        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+        EffectValue::Flat(1.0)
+    )
+)]
+#[case(
+    "increase this item's cooldown by 2 second(s) for the fight.",
+    Effect::CooldownReduction(CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random), EffectValue::Flat(-2.0))
+)]
+#[case(
+    "reduce this item's cooldown by 10% for the fight.",
+    Effect::CooldownReduction(
+        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+        EffectValue::Percentage(10.0)
+    )
+)]
+#[case(
+    "reduce the cooldown of your other items by 10% for this fight.",
+    Effect::CooldownReduction(
+        CardTarget(
+            usize::MAX,
+            TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf, TargetSelection::Random),
+        EffectValue::Percentage(10.0)
+    )
+)]
+fn test_cooldown_reduction_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+#[case(
+    "adjacent items have their cooldown reduced by 20%.",
+    TargetCondition::Adjacent,
+    20.0
+)]
+#[case(
+    "your other tools have their cooldowns reduced by 5%.",
+    TargetCondition::HasOwner(PlayerTarget::Player)
+        & !TargetCondition::IsSelf
+        & TargetCondition::from_tags("tool").unwrap(),
+    5.0
+)]
+fn test_cooldown_modifier_tooltip(
+    #[case] tooltip: &str,
+    #[case] condition: TargetCondition,
+    #[case] reduction: f64,
+) {
+    assert_eq!(
+        Tooltip::try_from(tooltip).unwrap(),
+        Tooltip::StaticModifier(Modifier::GrantCooldownReduction(
+            condition,
+            Percentage::from_percentage_value(reduction)
+        ))
+    );
+}
+
+#[rstest]
+#[case(EffectValue::Flat(1.0), 2.0)]
+#[case(EffectValue::Flat(-2.0), 5.0)]
+#[case(EffectValue::Percentage(50.0), 1.5)]
+#[case(EffectValue::Flat(10.0), MIN_COOLDOWN.as_secs_f64())]
+#[case(EffectValue::Percentage(100.0), MIN_COOLDOWN.as_secs_f64())]
+fn test_reduce_cooldown(#[case] reduction: EffectValue<f64>, #[case] expected: f64) {
+    let mut card = fang();
+    card.reduce_cooldown(&reduction);
+    assert_eq!(card.cooldown, seconds(expected));
+}
+
+#[rstest]
+fn test_percentage_reductions_apply_to_base_cooldown() {
+    let mut card = fang();
+    card.reduce_cooldown(&EffectValue::Percentage(25.0));
+    card.reduce_cooldown(&EffectValue::Percentage(25.0));
+    assert_eq!(card.cooldown, seconds(1.5));
+}
+
+#[rstest]
+fn test_progress_beyond_new_cooldown_activates_once() {
+    let mut card = fang();
+    for _ in 0..seconds(2.5).0 {
+        assert!(card.tick().is_empty());
+    }
+    card.reduce_cooldown(&EffectValue::Flat(2.0));
+    let uses = card
+        .tick()
+        .iter()
+        .filter(|e| matches!(e, CombatEvent::CardUsed(..)))
+        .count();
+    assert_eq!(uses, 1);
+}
+
+#[rstest]
+fn test_cooldown_modifiers_from_board() {
+    let simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Hourglass", Tier::Gold),
+        ]),
+        opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let cooldowns: Vec<GameTicks> = simulation
+        .cards
+        .values()
+        .filter(|card| card.inner.name == "Fang")
+        .map(|card| card.cooldown)
+        .collect();
+    assert_eq!(cooldowns, vec![seconds(3.0 * 0.88), seconds(3.0)]);
+}
@@ -0,0 +1,143 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{
+    DerivedValue, Enchantment, Modifier, Percentage, PlayerTarget, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{CardModification, CardTemplate, CombatEvent, Simulation, SimulationTemplate};
+
+#[rstest]
+#[case(
+    "this deals quadruple crit damage.",
+    Tooltip::StaticModifier(Modifier::QuadrupleCritDamage)
+)]
+#[case(
+    "this has double crit damage.",
+    Tooltip::StaticModifier(Modifier::DoubleCritDamage)
+)]
+fn test_crit_damage_tooltip(#[case] tooltip: &str, #[case] expected: Tooltip) {
+    assert_eq!(Tooltip::from_or_raw(tooltip), expected);
+}
+
+#[rstest]
+fn test_enchantment_crit_chance() {
+    let card = CardTemplate {
+        modifications: vec![CardModification::Enchanted(Enchantment::Deadly)],
+        ..card_template("Fang", Tier::Bronze)
+    }
+    .create_card_on_board(0, PlayerTarget::Player, Default::default())
+    .unwrap();
+    assert_eq!(card.compute_crit_chance(), Percentage::from_fraction(0.5));
+}
+
+#[rstest]
+fn test_crit_damage_multiplier() {
+    let card = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    assert_eq!(card.crit_damage_multiplier(), 2);
+    let card = CardTemplate {
+        modifications: vec![CardModification::Enchanted(Enchantment::Deadly)],
+        ..card_template("Musket", Tier::Silver)
+    }
+    .create_card_on_board(0, PlayerTarget::Player, Default::default())
+    .unwrap();
+    assert_eq!(card.crit_damage_multiplier(), 4);
+}
+
+#[rstest]
+#[case(vec![], 2)]
+#[case(vec![Modifier::DoubleCritDamage], 4)]
+#[case(vec![Modifier::QuadrupleCritDamage], 8)]
+#[case(vec![Modifier::DoubleCritDamage, Modifier::QuadrupleCritDamage], 8)]
+#[case(vec![Modifier::DoubleCritDamage, Modifier::DoubleCritDamage], 4)]
+fn test_crit_damage_modifiers_do_not_stack(
+    #[case] modifiers: Vec<Modifier>,
+    #[case] expected: u32,
+) {
+    let mut card = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    card.tooltips
+        .extend(modifiers.into_iter().map(Tooltip::StaticModifier));
+    assert_eq!(card.crit_damage_multiplier(), expected);
+}
+
+#[rstest]
+fn test_board_crit_chance() -> Result<(), Box<dyn std::error::Error>> {
+    let simulation: Simulation = SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Ramrod", Tier::Silver),
+            card_template("Revolver", Tier::Bronze),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    }
+    .try_into()?;
+    let fang = &simulation.player.card_ids[0];
+    let revolver = &simulation.player.card_ids[2];
+    assert_eq!(simulation.crit_chance(fang), Percentage::from_fraction(0.0));
+    assert!(simulation.crit_chance(revolver).as_fraction() > 0.0);
+    Ok(())
+}
+
+#[rstest]
+fn test_every_use_of_certain_crit_crits() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "musket".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Musket", Tier::Silver)]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let events = &result.inner_ref().events;
+    let uses = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::CardUsed(..)))
+        .count();
+    let crits = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Crit(..)))
+        .count();
+    assert!(uses > 0);
+    assert_eq!(uses, crits);
+    Ok(())
+}
+
+#[rstest]
+#[case(0.0, 5)]
+#[case(1.0, 10)]
+fn test_crit_doubles_damage(#[case] crit_chance: f64, #[case] expected_damage: u32) {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![card_template("Fang", Tier::Bronze)]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang = simulation.player.card_ids[0];
+    simulation.cards.get_mut(&fang).unwrap().stats.crit_chance =
+        Percentage::from_fraction(crit_chance);
+
+    let result = simulation.run_once();
+    let events = &result.inner_ref().events;
+    let damage: Vec<u32> = events
+        .iter()
+        .filter_map(|e| match e.1 {
+            CombatEvent::DealDamage(_, DerivedValue::Constant(d), _, id) if id == fang => Some(d),
+            _ => None,
+        })
+        .collect();
+    let crits = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Crit(..)))
+        .count();
+    assert!(!damage.is_empty());
+    assert!(damage.iter().all(|d| *d == expected_damage));
+    assert_eq!(crits, if crit_chance > 0.0 { damage.len() } else { 0 });
+}
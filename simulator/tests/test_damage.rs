@@ -0,0 +1,112 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{Percentage, PlayerTarget, Tier};
+use rstest::rstest;
+use simulator::{
+    CombatEvent, DamageMitigation, DamageRecord, Player, PlayerTemplate, Simulation,
+    SimulationResult, SimulationTemplate,
+};
+
+fn ticks(result: &SimulationResult) -> usize {
+    result
+        .inner_ref()
+        .events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Tick(..)))
+        .count()
+}
+
+fn empty_player() -> Player {
+    player(vec![]).create_player(vec![]).unwrap()
+}
+
+#[rstest]
+#[case(DamageMitigation::default(), 0, DamageRecord { pre_mitigation: 100, mitigated: 0, absorbed_by_shield: 0, health_lost: 100 })]
+#[case(DamageMitigation::default(), 30, DamageRecord { pre_mitigation: 100, mitigated: 0, absorbed_by_shield: 30, health_lost: 70 })]
+#[case(DamageMitigation::default(), 300, DamageRecord { pre_mitigation: 100, mitigated: 0, absorbed_by_shield: 100, health_lost: 0 })]
+#[case(
+    DamageMitigation { percentage: Percentage::from_percentage_value(20.0), flat: 0 },
+    30,
+    DamageRecord { pre_mitigation: 100, mitigated: 20, absorbed_by_shield: 30, health_lost: 50 }
+)]
+#[case(
+    DamageMitigation { percentage: Percentage::from_percentage_value(50.0), flat: 10 },
+    0,
+    DamageRecord { pre_mitigation: 100, mitigated: 60, absorbed_by_shield: 0, health_lost: 40 }
+)]
+#[case(
+    DamageMitigation { percentage: Percentage::from_percentage_value(150.0), flat: 0 },
+    0,
+    DamageRecord { pre_mitigation: 100, mitigated: 100, absorbed_by_shield: 0, health_lost: 0 }
+)]
+fn test_damage_pipeline(
+    #[case] mitigation: DamageMitigation,
+    #[case] shield: u32,
+    #[case] expected: DamageRecord,
+) {
+    let mut player = empty_player();
+    player.shield(shield);
+    let record = player.take_damage(100, mitigation);
+    assert_eq!(record, expected);
+    assert_eq!(player.health.current(), 1000 - expected.health_lost as i64);
+    assert_eq!(
+        player.shield_stacks,
+        (shield - expected.absorbed_by_shield) as i64
+    );
+}
+
+#[rstest]
+#[case(Tier::Gold, 0.1)]
+#[case(Tier::Diamond, 0.2)]
+fn test_mitigation_from_board(#[case] tier: Tier, #[case] expected: f64) {
+    let simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![card_template("Bunker", tier)]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let mitigation = simulation.damage_mitigation(PlayerTarget::Player);
+    assert!((mitigation.percentage.as_fraction() - expected).abs() < f64::EPSILON);
+    assert_eq!(
+        simulation
+            .damage_mitigation(PlayerTarget::Opponent)
+            .percentage
+            .as_fraction(),
+        0.0
+    );
+}
+
+#[rstest]
+fn test_mitigation_slows_down_defeat() -> Result<(), Box<dyn std::error::Error>> {
+    // Both fights have to end before the sandstorm, which mitigation does not reduce
+    let opponent = player(vec![card_template("Fang", Tier::Bronze)]);
+    let without_bunker = run_simulation(
+        "without bunker".to_string(),
+        SimulationTemplate {
+            player: PlayerTemplate {
+                health: 20,
+                ..player(vec![])
+            },
+            opponent: opponent.clone(),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let with_bunker = run_simulation(
+        "with bunker".to_string(),
+        SimulationTemplate {
+            player: PlayerTemplate {
+                health: 20,
+                ..player(vec![card_template("Bunker", Tier::Diamond)])
+            },
+            opponent,
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    assert!(!with_bunker.inner_ref().sandstorm);
+    assert!(ticks(&with_bunker) > ticks(&without_bunker));
+    Ok(())
+}
@@ -0,0 +1,172 @@
+mod aux;
+
+use aux::{card_id, card_template, player, run_simulation, SEED};
+use models::v2_0_0::{
+    CardTarget, Condition, Effect, EffectValue, Enchantment, Modifier, PlayerTarget, Size, Tag,
+    TargetCondition, TargetSelection, Tier, Tooltip,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rstest::rstest;
+use simulator::{
+    CardModification, CardTemplate, CombatEvent, ConditionalTooltip, DispatchableEvent,
+    GlobalCardId, Simulation, SimulationResult, SimulationTemplate, TriggerId,
+};
+
+fn uses_after_first_destroy(result: &SimulationResult, id: GlobalCardId) -> Option<usize> {
+    let events = &result.inner_ref().events;
+    let destroyed_at = events
+        .iter()
+        .position(|e| matches!(e.1, CombatEvent::Destroy(..)))?;
+    Some(
+        events[destroyed_at..]
+            .iter()
+            .filter(|e| matches!(e.1, CombatEvent::CardUsed(used) if used == id))
+            .count(),
+    )
+}
+
+#[rstest]
+#[case(
+    "destroy this.",
+    Effect::Destroy(CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random))
+)]
+#[case(
+    "destroy an enemy item for the fight.",
+    Effect::Destroy(CardTarget(
+        1,
+        TargetCondition::HasOwner(PlayerTarget::Opponent),
+        TargetSelection::Random
+    ))
+)]
+#[case(
+    "destroy a small enemy item for the fight.",
+    Effect::Destroy(CardTarget(
+        1,
+        TargetCondition::HasOwner(PlayerTarget::Opponent) & TargetCondition::HasSize(Size::Small), TargetSelection::Random))
+)]
+#[case(
+    "destroy 3 enemy items for the fight.",
+    Effect::Destroy(CardTarget(
+        3,
+        TargetCondition::HasOwner(PlayerTarget::Opponent),
+        TargetSelection::Random
+    ))
+)]
+fn test_destroy_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+fn test_destroyed_card_stops_ticking() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "singularity".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Singularity", Tier::Legendary)]),
+            opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let fang = card_id(&result, "Fang");
+    assert_eq!(uses_after_first_destroy(&result, fang), Some(0));
+    Ok(())
+}
+
+#[rstest]
+fn test_radiant_cards_cannot_be_destroyed() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "singularity vs radiant".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Singularity", Tier::Legendary)]),
+            opponent: player(vec![CardTemplate {
+                name: "Fang".to_string(),
+                tier: Tier::Bronze,
+                modifications: vec![CardModification::Enchanted(Enchantment::Radiant)],
+            }]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let fang = card_id(&result, "Fang");
+    assert!(uses_after_first_destroy(&result, fang).unwrap() > 0);
+    Ok(())
+}
+
+#[rstest]
+fn test_destroyed_card_stops_granting_weapon_damage() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Bar of Gold", Tier::Bronze),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang = simulation.player.card_ids[0];
+    let source = simulation.player.card_ids[1];
+    simulation
+        .cards
+        .get_mut(&source)
+        .unwrap()
+        .conditional_tooltips
+        .push(ConditionalTooltip {
+            condition: Condition::HasCardOfTag(Tag::Weapon),
+            tooltip: Tooltip::StaticModifier(Modifier::WeaponDamage(EffectValue::Flat(6))),
+            active: false,
+            trigger_id: TriggerId::new(),
+        });
+    simulation.update_conditional_tooltips();
+    assert_eq!(simulation.cards[&fang].stats.damage, 5 + 6);
+
+    simulation.destroy_card(&source);
+    assert_eq!(simulation.cards[&fang].stats.damage, 5);
+
+    // Board modifiers are reverted and applied again around a transform, without the destroyed
+    // card
+    let inner = simulation.cards[&fang].inner.clone();
+    simulation
+        .transform_card(&fang, inner, Tier::Bronze)
+        .unwrap();
+    assert_eq!(simulation.cards[&fang].stats.damage, 5);
+}
+
+#[rstest]
+fn test_use_queued_before_destroy_does_not_happen() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Fang", Tier::Bronze),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+    .with_channel(sender);
+    let source = simulation.player.card_ids[0];
+    let target = simulation.player.card_ids[1];
+    // The charge queues a use of the other Fang behind its destruction in the same tick
+    let other = CardTarget(
+        1,
+        TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf,
+        TargetSelection::Random,
+    );
+    simulation.cards.get_mut(&source).unwrap().cooldown_effects =
+        vec![Effect::Charge(other.clone(), 10.0), Effect::Destroy(other)];
+
+    simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let events: Vec<DispatchableEvent> = receiver.try_iter().collect();
+    let destroyed_at = events
+        .iter()
+        .position(|e| matches!(e, DispatchableEvent::CardDestroyed(card) if card.id == target))
+        .unwrap();
+    assert!(events[..destroyed_at]
+        .iter()
+        .any(|e| matches!(e, DispatchableEvent::CardCharged(card, ..) if card.id == target)));
+    assert!(!events[destroyed_at..]
+        .iter()
+        .any(|e| matches!(e, DispatchableEvent::CardUsed(card) if card.id == target)));
+}
@@ -56,3 +56,21 @@ fn test_simulations_draw(
     );
     Ok(())
 }
+
+#[rstest]
+fn test_sandstorm_draw(
+    #[files("tests/simulations/draw/sandstorm/*.toml")] path: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let template = read_simulation(&path)?;
+    let result = run_simulation(format!("{:?}", path.file_name().unwrap()), template)?;
+    assert!(
+        matches!(
+            result,
+            SimulationResult::Draw(SimulationDrawType::Sandstorm, ..)
+        ),
+        "Simulation `{:?}` failed: Expected `Draw(Sandstorm)` got `{}`",
+        path.file_name().unwrap(),
+        result.short_str(),
+    );
+    Ok(())
+}
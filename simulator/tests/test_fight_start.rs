@@ -0,0 +1,70 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{
+    CardTarget, Condition, Effect, EffectEvent, PlayerTarget, Tag, TargetCondition,
+    TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{CombatEvent, Simulation, SimulationTemplate, TaggedCombatEvent};
+
+fn events_before_first_tick(events: &[TaggedCombatEvent]) -> &[TaggedCombatEvent] {
+    events
+        .split(|e| matches!(e.1, CombatEvent::Tick(..)))
+        .next()
+        .unwrap_or_default()
+}
+
+#[rstest]
+fn test_conditional_fight_start_tooltip() {
+    assert_eq!(
+        Tooltip::from_or_raw("if you have a vehicle, at the start of each fight, use this."),
+        Tooltip::Conditional(
+            Condition::HasCardOfTag(Tag::Vehicle),
+            Box::new(Tooltip::When(EffectEvent::OnFightStart(Effect::Use(
+                CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random)
+            ))))
+        )
+    );
+}
+
+#[rstest]
+fn test_fight_start_effects_resolve_before_first_tick() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "fight start".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Magma Core", Tier::Bronze),
+                card_template("Icicle", Tier::Bronze),
+            ]),
+            opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let opening = events_before_first_tick(&result.inner_ref().events);
+    assert!(opening.iter().any(|e| matches!(
+        e,
+        TaggedCombatEvent(PlayerTarget::Player, CombatEvent::ApplyBurn(..))
+    )));
+    assert!(opening.iter().any(|e| matches!(
+        e,
+        TaggedCombatEvent(PlayerTarget::Player, CombatEvent::Freeze(..))
+    )));
+    Ok(())
+}
+
+#[rstest]
+fn test_condition_holds() -> Result<(), Box<dyn std::error::Error>> {
+    let simulation: Simulation = SimulationTemplate {
+        player: player(vec![card_template("Bill Dozer", Tier::Silver)]),
+        opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+        seed: None,
+        sandstorm: Default::default(),
+    }
+    .try_into()?;
+    let has_vehicle = Condition::HasCardOfTag(Tag::Vehicle);
+    assert!(simulation.condition_holds(PlayerTarget::Player, &has_vehicle));
+    assert!(!simulation.condition_holds(PlayerTarget::Opponent, &has_vehicle));
+    Ok(())
+}
@@ -0,0 +1,113 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{
+    Condition, DerivedValue, Effect, EffectEvent, Enchantment, GlobalEvent, PlayerTarget, Tag,
+    Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{
+    CardModification, CardTemplate, CombatEvent, ConditionalTooltip, PlayerHealth, PlayerTemplate,
+    Simulation, SimulationTemplate, TriggerId,
+};
+
+fn player_with_health(health: u64, card_templates: Vec<CardTemplate>) -> PlayerTemplate {
+    PlayerTemplate {
+        health,
+        ..player(card_templates)
+    }
+}
+
+#[rstest]
+#[case(
+    "the first time you fall below 50% health each fight, use this.",
+    GlobalEvent::PlayerFallsBelowHpPercentage(50.0)
+)]
+#[case(
+    "the first time your enemy falls below half health each fight, use this.",
+    GlobalEvent::EnemyFallsBelowHpPercentage(50.0)
+)]
+fn test_first_time_tooltip(#[case] tooltip: &str, #[case] expected: GlobalEvent) {
+    assert!(matches!(
+        Tooltip::from_or_raw(tooltip),
+        Tooltip::When(EffectEvent::OnFirstTime(global_event, Effect::Use(..))) if global_event == expected
+    ));
+}
+
+#[rstest]
+fn test_global_event_holds() -> Result<(), Box<dyn std::error::Error>> {
+    let mut simulation: Simulation = SimulationTemplate {
+        player: player_with_health(100, vec![]),
+        opponent: player_with_health(100, vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    }
+    .try_into()?;
+    let below_half = GlobalEvent::PlayerFallsBelowHpPercentage(50.0);
+    let enemy_below_half = GlobalEvent::EnemyFallsBelowHpPercentage(50.0);
+    assert!(!simulation.global_event_holds(PlayerTarget::Player, &below_half));
+
+    simulation.player.health = PlayerHealth(49, 100);
+    assert!(simulation.global_event_holds(PlayerTarget::Player, &below_half));
+    assert!(!simulation.global_event_holds(PlayerTarget::Player, &enemy_below_half));
+    assert!(!simulation.global_event_holds(PlayerTarget::Opponent, &below_half));
+    assert!(simulation.global_event_holds(PlayerTarget::Opponent, &enemy_below_half));
+    Ok(())
+}
+
+#[rstest]
+fn test_first_time_fires_once_per_fight() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "restorative hourglass".to_string(),
+        SimulationTemplate {
+            player: player_with_health(
+                100,
+                vec![CardTemplate {
+                    modifications: vec![CardModification::Enchanted(Enchantment::Restorative)],
+                    ..card_template("Hourglass", Tier::Bronze)
+                }],
+            ),
+            opponent: player_with_health(1000, vec![card_template("Fang", Tier::Silver)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let heals = result
+        .inner_ref()
+        .events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Heal(..)))
+        .count();
+    assert_eq!(heals, 1);
+    Ok(())
+}
+
+#[rstest]
+fn test_first_time_trigger_keeps_its_id_when_earlier_triggers_go_away() {
+    let mut card = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    for percentage in [50.0, 25.0] {
+        card.conditional_tooltips.push(ConditionalTooltip {
+            condition: Condition::HasCardOfTag(Tag::Weapon),
+            tooltip: Tooltip::When(EffectEvent::OnFirstTime(
+                GlobalEvent::PlayerFallsBelowHpPercentage(percentage),
+                Effect::Heal(PlayerTarget::Player, DerivedValue::Constant(10)),
+            )),
+            active: false,
+            trigger_id: TriggerId::new(),
+        });
+    }
+    let second = card.conditional_tooltips[1].trigger_id;
+    card.set_conditional_tooltip_active(0, true);
+    card.set_conditional_tooltip_active(1, true);
+    assert_eq!(card.first_time_triggers[1].0, second);
+
+    // A latch on the second trigger must not move to another trigger when the first goes away
+    card.set_conditional_tooltip_active(0, false);
+    assert_eq!(card.first_time_triggers.len(), 1);
+    assert_eq!(card.first_time_triggers[0].0, second);
+    card.set_conditional_tooltip_active(1, false);
+    card.set_conditional_tooltip_active(1, true);
+    assert_eq!(card.first_time_triggers[0].0, second);
+}
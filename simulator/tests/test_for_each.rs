@@ -0,0 +1,177 @@
+mod aux;
+
+use aux::{card_template, player};
+use models::v2_0_0::{
+    CardDerivedProperty, CardTarget, DerivedValue, Effect, EffectEvent, Modifier, PlayerTarget,
+    Tag, TargetCondition, TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{GlobalCardId, PlayerTemplate, Simulation, SimulationTemplate};
+
+fn board() -> PlayerTemplate {
+    PlayerTemplate {
+        regen: 1,
+        ..player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Runic Blade", Tier::Gold),
+            card_template("Fang", Tier::Bronze),
+        ])
+    }
+}
+
+fn simulation() -> Simulation {
+    Simulation::try_from(SimulationTemplate {
+        player: board(),
+        opponent: board(),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+}
+
+fn count(condition: TargetCondition, per_match: f32) -> DerivedValue<u32> {
+    DerivedValue::FromCard(
+        CardTarget(usize::MAX, condition, TargetSelection::Random),
+        CardDerivedProperty::Count,
+        per_match,
+    )
+}
+
+fn your(tag: Tag) -> TargetCondition {
+    TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(tag)
+}
+
+#[rstest]
+#[case(
+    "shield 10 for each aquatic item you have.",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::Shield(
+        PlayerTarget::Player,
+        count(your(Tag::Aquatic), 10.0)
+    )))
+)]
+#[case(
+    "burn 0.5 for each item to the left of this.",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::Burn(
+        PlayerTarget::Opponent,
+        count(TargetCondition::LeftOf, 0.5)
+    )))
+)]
+#[case(
+    "deal 20 damage for each friend you have.",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::DealDamage(
+        PlayerTarget::Opponent,
+        count(your(Tag::Friend), 20.0)
+    )))
+)]
+#[case(
+    "this has +1 multicast for each weapon or friend your enemy has.",
+    Tooltip::StaticModifier(Modifier::IncreasedMulticast(count(
+        TargetCondition::HasOwner(PlayerTarget::Opponent)
+            & (TargetCondition::HasTag(Tag::Weapon) | TargetCondition::HasTag(Tag::Friend)),
+        1.0
+    )))
+)]
+#[case(
+    "this has +1 multicast for each other aquatic item you have.",
+    Tooltip::StaticModifier(Modifier::IncreasedMulticast(count(
+        TargetCondition::HasOwner(PlayerTarget::Player)
+            & !TargetCondition::IsSelf
+            & TargetCondition::HasTag(Tag::Aquatic),
+        1.0
+    )))
+)]
+#[case(
+    "for each adjacent tool or food item, this gains +1 multicast.",
+    Tooltip::StaticModifier(Modifier::IncreasedMulticast(count(
+        TargetCondition::Adjacent
+            & (TargetCondition::HasTag(Tag::Tool) | TargetCondition::HasTag(Tag::Food)),
+        1.0
+    )))
+)]
+#[case(
+    "this has +1 multicast for each adjacent property.",
+    Tooltip::StaticModifier(Modifier::IncreasedMulticast(count(
+        TargetCondition::Adjacent & TargetCondition::HasTag(Tag::Property),
+        1.0
+    )))
+)]
+#[case(
+    "you have 2 regen for each ammo item you have.",
+    Tooltip::StaticModifier(Modifier::Regen(count(your(Tag::Ammo), 2.0)))
+)]
+fn test_for_each_tooltip(#[case] tooltip: &str, #[case] expected: Tooltip) {
+    assert_eq!(Tooltip::try_from(tooltip).unwrap(), expected);
+}
+
+#[rstest]
+fn test_count_follows_the_board() {
+    let mut simulation = simulation();
+    let ids = simulation.player.card_ids.clone();
+    let value = count(your(Tag::Weapon), 2.0);
+    assert_eq!(simulation.resolve_effect_value(&value, &ids[0]).unwrap(), 6);
+
+    simulation.cards.get_mut(&ids[2]).unwrap().destroy();
+    assert_eq!(simulation.resolve_effect_value(&value, &ids[0]).unwrap(), 4);
+}
+
+#[rstest]
+fn test_item_regen_is_reevaluated_when_the_board_changes() {
+    let mut simulation = simulation();
+    let ids: Vec<GlobalCardId> = simulation.player.card_ids.clone();
+    simulation
+        .cards
+        .get_mut(&ids[1])
+        .unwrap()
+        .tooltips
+        .push(Tooltip::StaticModifier(Modifier::Regen(count(
+            your(Tag::Weapon),
+            2.0,
+        ))));
+    simulation.update_item_regen();
+    assert_eq!(simulation.player.regeneration_stacks, 1 + 6);
+    assert_eq!(simulation.opponent.regeneration_stacks, 1);
+
+    simulation.cards.get_mut(&ids[0]).unwrap().destroy();
+    simulation.update_conditional_tooltips();
+    assert_eq!(simulation.player.regeneration_stacks, 1 + 4);
+
+    // The regen goes away with the item granting it
+    simulation.cards.get_mut(&ids[1]).unwrap().destroy();
+    simulation.update_conditional_tooltips();
+    assert_eq!(simulation.player.regeneration_stacks, 1);
+}
+
+#[rstest]
+fn test_derived_damage_is_read_from_the_target() {
+    let mut simulation = simulation();
+    let ids = simulation.player.card_ids.clone();
+    // The Runic Blade deals damage equal to its own value
+    simulation.cards.get_mut(&ids[1]).unwrap().cooldown_effects = vec![Effect::DealDamage(
+        PlayerTarget::Opponent,
+        DerivedValue::FromCard(
+            CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+            CardDerivedProperty::Value,
+            1.0,
+        ),
+    )];
+    let blade_damage = DerivedValue::FromCard(
+        CardTarget(1, TargetCondition::Adjacent, TargetSelection::Random),
+        CardDerivedProperty::Damage,
+        1.0,
+    );
+    let blade_value = simulation.cards[&ids[1]].stats.value as f32;
+    assert_ne!(blade_value, simulation.cards[&ids[0]].stats.value as f32);
+    assert_eq!(
+        simulation
+            .derive_value(blade_damage.clone(), &ids[0])
+            .unwrap(),
+        blade_value
+    );
+
+    // Damage derived from damage is refused rather than followed
+    simulation.cards.get_mut(&ids[1]).unwrap().cooldown_effects = vec![Effect::DealDamage(
+        PlayerTarget::Opponent,
+        blade_damage.clone(),
+    )];
+    assert!(simulation.derive_value(blade_damage, &ids[0]).is_err());
+}
@@ -0,0 +1,54 @@
+use gamedata::v2_0_0::{
+    CONSTRUCT_CARD_BY_ID, CONSTRUCT_CARD_BY_NAME, CONSTRUCT_ENCOUNTER_BY_ID,
+    CONSTRUCT_ENCOUNTER_BY_NAME, CONSTRUCT_SKILL_BY_ID, CONSTRUCT_SKILL_BY_NAME, ENCOUNTERS_BY_DAY,
+};
+use models::v2_0_0::EncounterDay;
+use rstest::rstest;
+
+#[rstest]
+fn test_cards_by_id_match_cards_by_name() {
+    assert_eq!(CONSTRUCT_CARD_BY_ID.len(), CONSTRUCT_CARD_BY_NAME.len());
+    for (id, construct) in CONSTRUCT_CARD_BY_ID.iter() {
+        let card = construct();
+        assert_eq!(card.id, *id);
+        assert_eq!(CONSTRUCT_CARD_BY_NAME.get(card.name).unwrap()().id, *id);
+    }
+}
+
+#[rstest]
+fn test_skills_by_id_match_skills_by_name() {
+    assert_eq!(CONSTRUCT_SKILL_BY_ID.len(), CONSTRUCT_SKILL_BY_NAME.len());
+    for (id, construct) in CONSTRUCT_SKILL_BY_ID.iter() {
+        let skill = construct();
+        assert_eq!(skill.id, *id);
+        assert_eq!(CONSTRUCT_SKILL_BY_NAME.get(skill.name).unwrap()().id, *id);
+    }
+}
+
+#[rstest]
+fn test_encounter_tables_are_consistent() {
+    for (id, construct) in CONSTRUCT_ENCOUNTER_BY_ID.iter() {
+        let encounter = construct();
+        assert_eq!(encounter.id, *id);
+        assert_eq!(
+            CONSTRUCT_ENCOUNTER_BY_NAME.get(encounter.name).unwrap()().name,
+            encounter.name
+        );
+        assert!(ENCOUNTERS_BY_DAY
+            .get(&encounter.day)
+            .unwrap()
+            .iter()
+            .any(|construct| construct().id == *id));
+    }
+    let by_day: usize = ENCOUNTERS_BY_DAY.values().map(Vec::len).sum();
+    assert_eq!(by_day, CONSTRUCT_ENCOUNTER_BY_ID.len());
+}
+
+#[rstest]
+fn test_encounters_by_day() {
+    let day_one = ENCOUNTERS_BY_DAY.get(&EncounterDay::Numeric(1)).unwrap();
+    assert!(!day_one.is_empty());
+    assert!(day_one
+        .iter()
+        .all(|construct| construct().day == EncounterDay::Numeric(1)));
+}
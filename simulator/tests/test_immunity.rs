@@ -0,0 +1,81 @@
+mod aux;
+
+use aux::{player, run_simulation};
+use models::v2_0_0::{PlayerTarget, Tier};
+use rstest::rstest;
+use simulator::{
+    CardTemplate, CombatEvent, GameTicks, Player, SimulationTemplate, TICKS_PER_SECOND,
+};
+
+fn immune_player(ticks: u128) -> Player {
+    let mut player = player(vec![]).create_player(vec![]).unwrap();
+    player.grant_immunity(GameTicks(ticks));
+    player
+}
+
+#[rstest]
+fn test_immunity_blocks_direct_damage() {
+    let mut player = immune_player(10);
+    player.shield(5);
+    player.take_damage(100, Default::default());
+    assert_eq!(player.health.current(), 1000);
+    assert_eq!(player.shield_stacks, 5);
+}
+
+#[rstest]
+fn test_immunity_blocks_damage_over_time() {
+    let mut player = immune_player(10);
+    player.burn(10);
+    player.poison(10);
+    player.tick();
+    assert_eq!(player.health.current(), 1000);
+    assert_eq!(player.burn_stacks, 8);
+    assert_eq!(player.poison_stacks, 10);
+}
+
+#[rstest]
+fn test_immunity_expires() {
+    let mut player = immune_player(*TICKS_PER_SECOND as u128);
+    for _ in 0..*TICKS_PER_SECOND {
+        assert!(player.is_immune());
+        player.tick();
+    }
+    assert!(!player.is_immune());
+    player.poison(10);
+    player.tick();
+    assert_eq!(player.health.current(), 990);
+}
+
+#[rstest]
+fn test_longer_immunity_wins() {
+    let mut player = immune_player(10);
+    player.grant_immunity(GameTicks(5));
+    assert_eq!(player.immunity_ticks, GameTicks(10));
+}
+
+#[rstest]
+fn test_invulnerability_potion() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "invulnerability potion".to_string(),
+        SimulationTemplate {
+            player: player(vec![CardTemplate {
+                name: "Invulnerability Potion".to_string(),
+                tier: Tier::Gold,
+                modifications: vec![],
+            }]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    assert_eq!(
+        result
+            .inner_ref()
+            .events
+            .iter()
+            .filter(|e| matches!(e.1, CombatEvent::DamageImmunity(PlayerTarget::Player, ..)))
+            .count(),
+        1
+    );
+    Ok(())
+}
@@ -0,0 +1,148 @@
+mod aux;
+
+use aux::{card_template, player, revolver, run_simulation};
+use models::v2_0_0::{
+    CardDerivedProperty, CardTarget, DerivedValue, Enchantment, Modifier, Percentage, PlayerTarget,
+    TargetCondition, TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{
+    CardModification, CardTemplate, CombatEvent, GlobalCardId, Simulation, SimulationTemplate,
+    TaggedCombatEvent,
+};
+
+fn from_own_damage(modifier: f32) -> DerivedValue<u32> {
+    DerivedValue::FromCard(
+        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+        CardDerivedProperty::Damage,
+        modifier,
+    )
+}
+
+#[rstest]
+fn test_lifesteal_tooltip() {
+    assert_eq!(
+        Tooltip::try_from("Lifesteal").unwrap(),
+        Tooltip::StaticModifier(Modifier::Lifesteal)
+    );
+}
+
+#[rstest]
+#[case(40, 0.5, 20)]
+#[case(0, 1.0, 0)]
+fn test_damage_linked_value_follows_damage_dealt(
+    #[case] dealt: u32,
+    #[case] modifier: f32,
+    #[case] expected: u32,
+) {
+    let source = revolver().id_for_simulation;
+    let event = CombatEvent::ApplyShield(PlayerTarget::Player, from_own_damage(modifier), source);
+    assert!(event.is_damage_linked());
+    assert!(matches!(
+        event.with_damage_dealt(dealt),
+        CombatEvent::ApplyShield(_, DerivedValue::Constant(v), _) if v == expected
+    ));
+}
+
+#[rstest]
+#[case(0.0, 5)]
+#[case(1.0, 10)]
+fn test_damage_linked_effect_follows_the_damage_of_its_use(
+    #[case] crit_chance: f64,
+    #[case] expected: u32,
+) {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![CardTemplate {
+            modifications: vec![CardModification::Enchanted(Enchantment::Shielded)],
+            ..card_template("Fang", Tier::Bronze)
+        }]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    simulation.cards.get_mut(&fang).unwrap().stats.crit_chance =
+        Percentage::from_fraction(crit_chance);
+
+    let result = simulation.run_once();
+    let values: Vec<(u32, u32)> = result
+        .inner_ref()
+        .events
+        .windows(2)
+        .filter_map(|pair| match (&pair[0].1, &pair[1].1) {
+            (
+                CombatEvent::DealDamage(_, DerivedValue::Constant(damage), _, id),
+                CombatEvent::ApplyShield(_, DerivedValue::Constant(shield), _),
+            ) if *id == fang => Some((*damage, *shield)),
+            _ => None,
+        })
+        .collect();
+    assert!(!values.is_empty());
+    // A crit doubles the damage once, the shield follows the damage without critting again
+    assert!(values.iter().all(|v| *v == (expected, expected)));
+}
+
+#[rstest]
+fn test_damage_linked_value_without_damage_dealt_reads_stats() {
+    let simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![card_template("Fang", Tier::Bronze)]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    let damage = simulation
+        .cards
+        .get(&fang)
+        .unwrap()
+        .stats
+        .effective_damage();
+    assert_eq!(
+        simulation
+            .resolve_effect_value(&from_own_damage(1.0), &fang)
+            .unwrap(),
+        damage
+    );
+}
+
+#[rstest]
+fn test_lifesteal_heals_owner_for_damage_dealt() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "runic blade lifesteal".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Runic Blade", Tier::Gold)]),
+            opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let events = &result.inner_ref().events;
+    let blade = events
+        .iter()
+        .find_map(|e| match e {
+            TaggedCombatEvent(PlayerTarget::Player, CombatEvent::DealDamage(.., id)) => Some(*id),
+            _ => None,
+        })
+        .unwrap();
+    let lifesteal: Vec<&TaggedCombatEvent> = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::Lifesteal(..)))
+        .collect();
+    assert!(!lifesteal.is_empty());
+    assert!(lifesteal.iter().all(|e| matches!(
+        e,
+        TaggedCombatEvent(
+            PlayerTarget::Player,
+            CombatEvent::Lifesteal(PlayerTarget::Player, amount, id)
+        ) if *amount > 0 && *id == blade
+    )));
+    // The enemy weapon has no lifesteal
+    let blade_hits = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::DealDamage(.., id) if id == blade))
+        .count();
+    assert_eq!(lifesteal.len(), blade_hits);
+    Ok(())
+}
@@ -0,0 +1,61 @@
+mod aux;
+
+use aux::{card_template, player};
+use models::v2_0_0::{Percentage, PlayerTarget, Tier};
+use rstest::rstest;
+use simulator::{Card, CardModification, CardTemplate, GameTicks, Simulation, SimulationTemplate};
+use std::time::Duration;
+
+fn create_card(name: &str, modifications: Vec<CardModification>) -> anyhow::Result<Card> {
+    CardTemplate {
+        modifications,
+        ..card_template(name, Tier::Bronze)
+    }
+    .create_card_on_board(0, PlayerTarget::Player, Default::default())
+}
+
+#[rstest]
+fn test_damage_modification() {
+    let base = create_card("Fang", vec![]).unwrap();
+    let card = create_card("Fang", vec![CardModification::Damage(10)]).unwrap();
+    assert_eq!(card.stats.damage, base.stats.damage + 10);
+}
+
+#[rstest]
+fn test_crit_modification() {
+    let base = create_card("Fang", vec![]).unwrap();
+    let card = create_card(
+        "Fang",
+        vec![CardModification::Crit(Percentage::from_fraction(0.25))],
+    )
+    .unwrap();
+    assert_eq!(
+        card.stats.crit_chance.as_fraction(),
+        base.stats.crit_chance.as_fraction() + 0.25
+    );
+}
+
+#[rstest]
+fn test_cooldown_modification() {
+    let simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![CardTemplate {
+            modifications: vec![CardModification::Cooldown(Percentage::from_fraction(0.5))],
+            ..card_template("Fang", Tier::Bronze)
+        }]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang = simulation.cards.values().next().unwrap();
+    assert_eq!(fang.cooldown, GameTicks::from(Duration::from_secs_f64(1.5)));
+}
+
+#[rstest]
+#[case(CardModification::Burn(5))]
+#[case(CardModification::Shield(5))]
+#[case(CardModification::Crit(Percentage::from_fraction(1.5)))]
+#[case(CardModification::Cooldown(Percentage::from_fraction(-0.1)))]
+fn test_invalid_modification(#[case] modification: CardModification) {
+    assert!(create_card("Fang", vec![modification]).is_err());
+}
@@ -0,0 +1,50 @@
+mod aux;
+
+use aux::{card_template, count_uses, player, FANG_CARD_TEMPLATE};
+use models::v2_0_0::{Enchantment, PlayerTarget, Tier};
+use rstest::rstest;
+use simulator::{Card, CardModification, CardTemplate, Simulation, SimulationTemplate};
+
+#[rstest]
+fn test_static_multicast() {
+    let mut card: Card = card_template("Haladie", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    assert_eq!(card.multicast, 2);
+    assert_eq!(count_uses(&card.use_card()), 2);
+}
+
+#[rstest]
+fn test_enchantment_multicast() {
+    let mut card: Card = CardTemplate {
+        modifications: vec![CardModification::Enchanted(Enchantment::Shiny)],
+        ..FANG_CARD_TEMPLATE.clone()
+    }
+    .create_card_on_board(0, PlayerTarget::Player, Default::default())
+    .unwrap();
+    assert_eq!(card.multicast, 2);
+    assert_eq!(count_uses(&card.use_card()), 2);
+}
+
+#[rstest]
+fn test_multicast_for_each_adjacent() -> Result<(), Box<dyn std::error::Error>> {
+    let simulation: Simulation = SimulationTemplate {
+        player: player(vec![
+            card_template("Black Pepper", Tier::Silver),
+            card_template("Hot Sauce", Tier::Gold),
+            card_template("Black Pepper", Tier::Silver),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    }
+    .try_into()?;
+    let mut hot_sauce = simulation
+        .cards
+        .get(&simulation.player.card_ids[1])
+        .unwrap()
+        .clone();
+    assert_eq!(hot_sauce.multicast, 3);
+    assert_eq!(count_uses(&hot_sauce.use_card()), 3);
+    Ok(())
+}
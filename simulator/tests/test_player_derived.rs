@@ -0,0 +1,156 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{
+    CardTarget, DerivedValue, Effect, PlayerDerivedProperty, PlayerTarget, TargetCondition,
+    TargetSelection, Tier,
+};
+use rstest::rstest;
+use simulator::{
+    CardTemplate, CombatEvent, GlobalCardId, PlayerTemplate, Simulation, SimulationTemplate,
+};
+
+fn player_with(health: u64, gold: u32, card_templates: Vec<CardTemplate>) -> PlayerTemplate {
+    PlayerTemplate {
+        health,
+        gold,
+        ..player(card_templates)
+    }
+}
+
+fn from_player(
+    player: PlayerTarget,
+    property: PlayerDerivedProperty,
+    modifier: f32,
+) -> DerivedValue<u32> {
+    DerivedValue::FromPlayer(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(player),
+            TargetSelection::Random,
+        ),
+        property,
+        modifier,
+    )
+}
+
+fn simulation() -> Simulation {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player_with(1000, 7, vec![card_template("Fang", Tier::Bronze)]),
+        opponent: player_with(500, 0, vec![card_template("Fang", Tier::Bronze)]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    simulation.player.shield_stacks = 30;
+    simulation.opponent.burn_stacks = 12;
+    simulation.opponent.poison_stacks = 4;
+    simulation
+}
+
+#[rstest]
+#[case(
+    "heal equal to 10% of your max health.",
+    Effect::Heal(
+        PlayerTarget::Player,
+        from_player(PlayerTarget::Player, PlayerDerivedProperty::MaximumHealth, 0.1)
+    )
+)]
+#[case(
+    "deal damage equal to your shield.",
+    Effect::DealDamage(
+        PlayerTarget::Opponent,
+        from_player(PlayerTarget::Player, PlayerDerivedProperty::Shield, 1.0)
+    )
+)]
+#[case(
+    "heal equal to your enemy's poison.",
+    Effect::Heal(
+        PlayerTarget::Player,
+        from_player(PlayerTarget::Opponent, PlayerDerivedProperty::Poison, 1.0)
+    )
+)]
+#[case(
+    "gain regen equal to 5% of your max health for the fight.",
+    Effect::Regen(
+        PlayerTarget::Player,
+        from_player(PlayerTarget::Player, PlayerDerivedProperty::MaximumHealth, 0.05)
+    )
+)]
+fn test_player_derived_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+#[case(
+    0,
+    PlayerTarget::Player,
+    PlayerDerivedProperty::MaximumHealth,
+    0.1,
+    100.0
+)]
+#[case(
+    0,
+    PlayerTarget::Player,
+    PlayerDerivedProperty::CurrentHealth,
+    1.0,
+    1000.0
+)]
+#[case(0, PlayerTarget::Player, PlayerDerivedProperty::Shield, 1.0, 30.0)]
+#[case(0, PlayerTarget::Player, PlayerDerivedProperty::Gold, 2.0, 14.0)]
+#[case(0, PlayerTarget::Opponent, PlayerDerivedProperty::Burn, 1.0, 12.0)]
+#[case(
+    0,
+    PlayerTarget::Opponent,
+    PlayerDerivedProperty::MaximumHealth,
+    1.0,
+    500.0
+)]
+// Player targets are relative to the owner of the source card
+#[case(1, PlayerTarget::Player, PlayerDerivedProperty::Poison, 1.0, 4.0)]
+#[case(1, PlayerTarget::Opponent, PlayerDerivedProperty::Shield, 1.0, 30.0)]
+fn test_derive_player_value(
+    #[case] source: usize,
+    #[case] player_target: PlayerTarget,
+    #[case] property: PlayerDerivedProperty,
+    #[case] modifier: f32,
+    #[case] expected: f32,
+) {
+    let simulation = simulation();
+    let source_id: GlobalCardId = *simulation.cards.get_index(source).unwrap().0;
+    let derived = simulation
+        .derive_value(from_player(player_target, property, modifier), &source_id)
+        .unwrap();
+    assert_eq!(derived, expected);
+}
+
+#[rstest]
+fn test_derive_player_value_fails_without_player() {
+    let simulation = simulation();
+    let source_id: GlobalCardId = simulation.player.card_ids[0];
+    let value = DerivedValue::FromPlayer(
+        CardTarget(1, TargetCondition::Adjacent, TargetSelection::Random),
+        PlayerDerivedProperty::Shield,
+        1.0,
+    );
+    assert!(simulation.derive_value(value, &source_id).is_err());
+}
+
+#[rstest]
+fn test_heal_from_max_health() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "vitality potion".to_string(),
+        SimulationTemplate {
+            player: player_with(1000, 0, vec![card_template("Vitality Potion", Tier::Gold)]),
+            opponent: player_with(1000, 0, vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    assert!(result
+        .inner_ref()
+        .events
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::Heal(_, DerivedValue::FromPlayer(..), _))));
+    Ok(())
+}
@@ -0,0 +1,122 @@
+mod aux;
+
+use aux::{card_template, player};
+use models::v2_0_0::{
+    CardTarget, DerivedValue, Effect, EffectEvent, Modifier, Percentage, PlayerTarget, Size, Tag,
+    TargetCondition, TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{GlobalCardId, PlayerTemplate, Simulation, SimulationTemplate};
+
+/// Occupies spaces 0, 1..3, 3..6 and 6
+fn board() -> PlayerTemplate {
+    player(vec![
+        card_template("Fang", Tier::Bronze),
+        card_template("Runic Blade", Tier::Gold),
+        card_template("Runic Great Axe", Tier::Silver),
+        card_template("Fang", Tier::Bronze),
+    ])
+}
+
+fn simulation() -> Simulation {
+    Simulation::try_from(SimulationTemplate {
+        player: board(),
+        opponent: board(),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+}
+
+#[rstest]
+#[case(
+    "haste the item to the left of this for 1 second(s).",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::Haste(
+        CardTarget(1, TargetCondition::AdjacentLeft, TargetSelection::Random),
+        1.0
+    )))
+)]
+#[case(
+    "charge your rightmost item 1 second(s).",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::Charge(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Rightmost, TargetSelection::Random),
+        1.0
+    )))
+)]
+#[case(
+    "when you use your leftmost item, burn 2.",
+    Tooltip::When(EffectEvent::OnCardUsed(
+        TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Leftmost,
+        Effect::Burn(PlayerTarget::Opponent, DerivedValue::Constant(2))
+    ))
+)]
+#[case(
+    "when you use the weapon to the right of this, burn 2.",
+    Tooltip::When(EffectEvent::OnCardUsed(
+        TargetCondition::AdjacentRight & TargetCondition::HasTag(Tag::Weapon),
+        Effect::Burn(PlayerTarget::Opponent, DerivedValue::Constant(2))
+    ))
+)]
+#[case(
+    "the weapon to the left of this has +50% crit chance.",
+    Tooltip::StaticModifier(Modifier::GrantCritChance(
+        TargetCondition::AdjacentLeft & TargetCondition::HasTag(Tag::Weapon),
+        Percentage::from_percentage_value(50.0)
+    ))
+)]
+fn test_positional_tooltip(#[case] tooltip: &str, #[case] expected: Tooltip) {
+    assert_eq!(Tooltip::try_from(tooltip).unwrap(), expected);
+}
+
+#[rstest]
+#[case(1, TargetCondition::AdjacentLeft, vec![0])]
+#[case(1, TargetCondition::AdjacentRight, vec![2])]
+#[case(1, TargetCondition::Adjacent, vec![0, 2])]
+#[case(2, TargetCondition::Adjacent, vec![1, 3])]
+#[case(1, TargetCondition::LeftOf, vec![0])]
+#[case(1, TargetCondition::RightOf, vec![2, 3])]
+#[case(0, TargetCondition::AdjacentLeft, vec![])]
+#[case(
+    3,
+    TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Leftmost,
+    vec![0]
+)]
+#[case(
+    0,
+    TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Rightmost,
+    vec![3]
+)]
+#[case(
+    0,
+    TargetCondition::HasOwner(PlayerTarget::Player)
+        & TargetCondition::HasSize(Size::Medium)
+        & TargetCondition::Leftmost,
+    vec![1]
+)]
+fn test_positional_targets(
+    #[case] source: usize,
+    #[case] condition: TargetCondition,
+    #[case] expected: Vec<usize>,
+) {
+    let simulation = simulation();
+    let ids = &simulation.player.card_ids;
+    let expected: Vec<GlobalCardId> = expected.into_iter().map(|i| ids[i]).collect();
+    assert_eq!(
+        simulation.get_cards_by_target(&ids[source], &condition),
+        expected
+    );
+}
+
+#[rstest]
+fn test_leftmost_skips_destroyed_cards() {
+    let mut simulation = simulation();
+    let ids = simulation.player.card_ids.clone();
+    simulation.cards.get_mut(&ids[0]).unwrap().destroy();
+    let condition = TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::Leftmost;
+    assert_eq!(
+        simulation.get_cards_by_target(&ids[3], &condition),
+        vec![ids[1]]
+    );
+}
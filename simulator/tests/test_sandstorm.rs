@@ -0,0 +1,135 @@
+mod aux;
+
+use aux::{player, read_simulation, run_simulation};
+use rstest::rstest;
+use simulator::{
+    CombatEvent, DamageRecord, GameTicks, PlayerTemplate, SandstormTemplate, Simulation,
+    SimulationResult, SimulationTemplate, DURATION_BEFORE_SANDSTORM,
+};
+use std::{path::PathBuf, time::Duration};
+
+#[rstest]
+fn test_sandstorm_victory(
+    #[files("tests/simulations/sandstorm/*.toml")] path: PathBuf,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let template = read_simulation(&path)?;
+    let result = run_simulation(format!("{:?}", path.file_name().unwrap()), template)?;
+    assert!(
+        matches!(result, SimulationResult::Victory(ref inner) if inner.sandstorm),
+        "Simulation `{:?}` failed: Expected `Victory (Sandstorm)` got `{}`",
+        path.file_name().unwrap(),
+        result.short_str()
+    );
+    Ok(())
+}
+
+#[rstest]
+fn test_sandstorm_ramps_up() -> Result<(), Box<dyn std::error::Error>> {
+    let player = PlayerTemplate {
+        health: 100,
+        ..player(vec![])
+    };
+    let mut simulation: Simulation = SimulationTemplate {
+        player: player.clone(),
+        opponent: player,
+        seed: None,
+        sandstorm: Default::default(),
+    }
+    .try_into()?;
+    assert!(!simulation.is_sandstorm_active());
+    assert_eq!(simulation.sandstorm_damage(), None);
+
+    let mut previous_damage = 0;
+    let sandstorm_start = GameTicks::from(*DURATION_BEFORE_SANDSTORM).0;
+    for tick in sandstorm_start..sandstorm_start * 2 {
+        simulation.ticks = tick;
+        if let Some(damage) = simulation.sandstorm_damage() {
+            assert!(damage > previous_damage);
+            previous_damage = damage;
+        }
+    }
+    assert!(previous_damage > 1);
+    Ok(())
+}
+
+#[rstest]
+fn test_sandstorm_ignores_immunity_but_not_shield() {
+    let mut player = PlayerTemplate {
+        health: 100,
+        ..player(vec![])
+    }
+    .create_player(vec![])
+    .unwrap();
+    player.grant_immunity(GameTicks(10));
+    player.shield(5);
+    let record = player.take_sandstorm_damage(8);
+    assert_eq!(
+        record,
+        DamageRecord {
+            pre_mitigation: 8,
+            mitigated: 0,
+            absorbed_by_shield: 5,
+            health_lost: 3,
+        }
+    );
+    assert_eq!(player.health.current(), 97);
+}
+
+#[rstest]
+fn test_sandstorm_follows_the_template_curve() -> Result<(), Box<dyn std::error::Error>> {
+    let mut simulation: Simulation = SimulationTemplate {
+        player: player(vec![]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: SandstormTemplate {
+            interval_ms: 1000,
+            base_damage: 10,
+            damage_increment: 5,
+        },
+    }
+    .try_into()?;
+    let sandstorm_start = GameTicks::from(*DURATION_BEFORE_SANDSTORM).0;
+    let second = GameTicks::from(Duration::from_secs(1)).0;
+    simulation.ticks = sandstorm_start;
+    assert_eq!(simulation.sandstorm_damage(), Some(10));
+    simulation.ticks = sandstorm_start + 1;
+    assert_eq!(simulation.sandstorm_damage(), None);
+    simulation.ticks = sandstorm_start + 2 * second;
+    assert_eq!(simulation.sandstorm_damage(), Some(20));
+    Ok(())
+}
+
+#[rstest]
+fn test_first_sandstorm_strike_decides_a_sandstorm_fight() -> Result<(), Box<dyn std::error::Error>>
+{
+    let result = run_simulation(
+        "first sandstorm strike".to_string(),
+        SimulationTemplate {
+            player: PlayerTemplate {
+                health: 1,
+                ..player(vec![])
+            },
+            opponent: PlayerTemplate {
+                health: 100,
+                ..player(vec![])
+            },
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let sandstorm_start = GameTicks::from(*DURATION_BEFORE_SANDSTORM).0;
+    let inner = match result {
+        SimulationResult::Defeat(ref inner) => inner,
+        _ => panic!("expected a defeat, got `{}`", result.short_str()),
+    };
+    assert!(inner.sandstorm);
+    assert_eq!(
+        inner
+            .events
+            .iter()
+            .filter(|e| matches!(e.1, CombatEvent::Tick(..)))
+            .count() as u128,
+        sandstorm_start + 1
+    );
+    Ok(())
+}
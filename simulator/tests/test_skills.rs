@@ -0,0 +1,117 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{PlayerTarget, Size, TargetCondition, Tier};
+use rstest::rstest;
+use simulator::{
+    CardTemplate, CombatEvent, GlobalCardId, PlayerTemplate, Simulation, SimulationTemplate,
+    TaggedCombatEvent,
+};
+
+fn player_with_skills(
+    card_templates: Vec<CardTemplate>,
+    skill_templates: Vec<CardTemplate>,
+) -> PlayerTemplate {
+    PlayerTemplate {
+        skill_templates,
+        ..player(card_templates)
+    }
+}
+
+fn simulation(skill_templates: Vec<CardTemplate>) -> anyhow::Result<Simulation> {
+    Simulation::try_from(SimulationTemplate {
+        player: player_with_skills(vec![card_template("Fang", Tier::Bronze)], skill_templates),
+        opponent: player_with_skills(vec![card_template("Fang", Tier::Bronze)], vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+}
+
+#[rstest]
+fn test_skill_static_modifiers_apply_to_board() {
+    let without_skill = simulation(vec![]).unwrap();
+    let with_skill = simulation(vec![card_template("Deadly Eye", Tier::Bronze)]).unwrap();
+    let fang: GlobalCardId = with_skill.player.card_ids[0];
+    assert_eq!(
+        with_skill.crit_chance(&fang).as_fraction(),
+        without_skill
+            .crit_chance(&without_skill.player.card_ids[0])
+            .as_fraction()
+            + 0.05
+    );
+    // The enemy weapon is unaffected
+    let enemy_fang: GlobalCardId = with_skill.opponent.card_ids[0];
+    assert_eq!(
+        with_skill.crit_chance(&enemy_fang).as_fraction(),
+        without_skill
+            .crit_chance(&without_skill.opponent.card_ids[0])
+            .as_fraction()
+    );
+}
+
+#[rstest]
+fn test_skills_take_no_board_space_and_cannot_be_targeted() {
+    let simulation = Simulation::try_from(SimulationTemplate {
+        player: player_with_skills(
+            vec![card_template("Fang", Tier::Bronze); 10],
+            vec![card_template("Deadly Eye", Tier::Bronze)],
+        ),
+        opponent: player_with_skills(vec![], vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let skill: GlobalCardId = simulation.player.skill_ids[0];
+    let targets =
+        simulation.get_cards_by_target(&skill, &TargetCondition::HasOwner(PlayerTarget::Player));
+    assert_eq!(targets, simulation.player.card_ids);
+}
+
+#[rstest]
+fn test_skill_fight_start_effects() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "ambush".to_string(),
+        SimulationTemplate {
+            player: player_with_skills(vec![], vec![card_template("Ambush", Tier::Gold)]),
+            opponent: player_with_skills(vec![card_template("Fang", Tier::Bronze)], vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let first = result.inner_ref().events.first();
+    assert!(matches!(
+        first,
+        Some(TaggedCombatEvent(
+            PlayerTarget::Player,
+            CombatEvent::DealDamage(..)
+        ))
+    ));
+    let skills: Vec<&str> = result
+        .inner_ref()
+        .skills
+        .iter()
+        .map(|s| s.name.as_str())
+        .collect();
+    assert_eq!(skills, vec!["Ambush"]);
+    assert!(result.inner_ref().cards.iter().all(|c| c.name != "Ambush"));
+    Ok(())
+}
+
+#[rstest]
+#[case(vec![card_template("Fang", Tier::Bronze)])]
+#[case(vec![card_template("Deadly Eye", Tier::Legendary)])]
+#[case(vec![card_template("Deadly Eye", Tier::Bronze), card_template("Deadly Eye", Tier::Silver)])]
+fn test_invalid_skills(#[case] skill_templates: Vec<CardTemplate>) {
+    assert!(simulation(skill_templates).is_err());
+}
+
+#[rstest]
+fn test_skills_have_no_size_or_value() {
+    let simulation = simulation(vec![card_template("Deadly Eye", Tier::Bronze)]).unwrap();
+    let skill = &simulation.cards[&simulation.player.skill_ids[0]];
+    assert_eq!(skill.stats.value, 0);
+    assert_eq!(skill.compute_cost(), 0);
+    for size in [Size::Small, Size::Medium, Size::Large] {
+        assert!(!skill.matches(&TargetCondition::HasSize(size), None));
+    }
+}
@@ -0,0 +1,122 @@
+mod aux;
+
+use aux::{card_id, card_template, player, run_simulation};
+use models::v2_0_0::{
+    CardTarget, DerivedValue, Effect, EffectValue, PlayerTarget, Tag, TargetCondition,
+    TargetSelection, Tier,
+};
+use rstest::rstest;
+use simulator::{CombatEvent, SimulationTemplate};
+
+fn your(tag: Tag) -> TargetCondition {
+    TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(tag)
+}
+
+#[rstest]
+#[case(
+    "your weapons gain +10 damage for the fight.",
+    Effect::IncreaseDamage(
+        CardTarget(usize::MAX, your(Tag::Weapon), TargetSelection::Random),
+        EffectValue::Flat(10)
+    )
+)]
+#[case(
+    "your other weapons gain +5 damage for the fight.",
+    Effect::IncreaseDamage(
+        CardTarget(
+            usize::MAX,
+            TargetCondition::HasOwner(PlayerTarget::Player)
+                & !TargetCondition::IsSelf
+                & TargetCondition::HasTag(Tag::Weapon), TargetSelection::Random),
+        EffectValue::Flat(5)
+    )
+)]
+#[case(
+    "this gains +12 damage for the fight.",
+    Effect::IncreaseDamage(
+        CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random),
+        EffectValue::Flat(12)
+    )
+)]
+#[case(
+    "your shield items gain +9 shield for the fight.",
+    Effect::GainShield(
+        CardTarget(usize::MAX, your(Tag::Shield), TargetSelection::Random),
+        EffectValue::Flat(9.0)
+    )
+)]
+#[case(
+    "your shield items gain +10 shield and your weapons +20 damage for the fight.",
+    Effect::MultiEffect(vec![
+        Effect::GainShield(CardTarget(usize::MAX, your(Tag::Shield), TargetSelection::Random), EffectValue::Flat(10.0)),
+        Effect::IncreaseDamage(CardTarget(usize::MAX, your(Tag::Weapon), TargetSelection::Random), EffectValue::Flat(20)),
+    ])
+)]
+fn test_stat_buff_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+fn test_double_damage_stat() {
+    let card = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    let mut with_double_damage = card.clone();
+    with_double_damage.stats.damage_bonus = 100;
+    assert_eq!(
+        with_double_damage.stats.effective_damage(),
+        2 * card.stats.effective_damage()
+    );
+}
+
+#[rstest]
+fn test_damage_buffs_last_for_the_fight() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "dishwasher".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Fang", Tier::Bronze),
+                card_template("Dishwasher", Tier::Bronze),
+            ]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let fang = card_id(&result, "Fang");
+    let damages: Vec<u32> = result
+        .inner_ref()
+        .events
+        .iter()
+        .filter_map(|e| match e.1 {
+            CombatEvent::DealDamage(_, DerivedValue::Constant(d), _, id) if id == fang => Some(d),
+            _ => None,
+        })
+        .collect();
+    assert!(damages.windows(2).all(|w| w[0] <= w[1]));
+    assert!(damages.last().unwrap() >= &(damages[0] + 10));
+    Ok(())
+}
+
+#[rstest]
+fn test_buffs_move_every_constant_of_a_kind() {
+    let mut card = card_template("Fang", Tier::Bronze)
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    card.cooldown_effects.push(Effect::DealDamage(
+        PlayerTarget::Opponent,
+        DerivedValue::Constant(20),
+    ));
+    card.stats.increase_damage(&EffectValue::Flat(10));
+    card.stats.damage_bonus = 100;
+
+    let damages: Vec<u32> = card
+        .cast(false)
+        .into_iter()
+        .filter_map(|e| match e {
+            CombatEvent::DealDamage(_, DerivedValue::Constant(d), ..) => Some(d),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(damages, vec![2 * (5 + 10), 2 * (20 + 10)]);
+}
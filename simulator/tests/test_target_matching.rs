@@ -1,7 +1,7 @@
 mod aux;
 
-use aux::{BAR_OF_GOLD_CARD_TEMPLATE, FANG_CARD_TEMPLATE};
-use models::v2_0_0::{PlayerTarget, TargetCondition};
+use aux::{card_template, BAR_OF_GOLD_CARD_TEMPLATE, FANG_CARD_TEMPLATE};
+use models::v2_0_0::{PlayerTarget, Size, Tag, TargetCondition, Tier};
 use rstest::rstest;
 use simulator::Card;
 
@@ -54,3 +54,38 @@ pub fn test_target_cooldown() {
     assert!(card_with_cooldown.matches(&TargetCondition::HasCooldown, None));
     assert!(!card_without_cooldown.matches(&TargetCondition::HasCooldown, None));
 }
+
+#[rstest]
+pub fn test_target_properties_are_read_from_the_candidate() {
+    let fang: Card = FANG_CARD_TEMPLATE
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+    let bar_of_gold: Card = BAR_OF_GOLD_CARD_TEMPLATE
+        .create_card_on_board(1, PlayerTarget::Player, Default::default())
+        .unwrap();
+    let runic_blade: Card = card_template("Runic Blade", Tier::Gold)
+        .create_card_on_board(2, PlayerTarget::Player, Default::default())
+        .unwrap();
+
+    let weapon = TargetCondition::HasTag(Tag::Weapon);
+    assert!(fang.matches(&weapon, Some(&runic_blade)));
+    assert!(!fang.matches(&weapon, Some(&bar_of_gold)));
+    assert!(bar_of_gold.matches(&weapon, Some(&fang)));
+
+    let small = TargetCondition::HasSize(Size::Small);
+    assert!(runic_blade.matches(&small, Some(&fang)));
+    assert!(!fang.matches(&small, Some(&runic_blade)));
+
+    assert!(bar_of_gold.matches(&TargetCondition::HasCooldown, Some(&fang)));
+    assert!(!fang.matches(&TargetCondition::HasCooldown, Some(&bar_of_gold)));
+}
+
+#[rstest]
+pub fn test_target_properties_fall_back_to_the_card_itself() {
+    let bar_of_gold: Card = BAR_OF_GOLD_CARD_TEMPLATE
+        .create_card_on_board(0, PlayerTarget::Player, Default::default())
+        .unwrap();
+
+    assert!(!bar_of_gold.matches(&TargetCondition::HasTag(Tag::Weapon), None));
+    assert!(bar_of_gold.matches(&TargetCondition::HasSize(Size::Small), None));
+}
@@ -0,0 +1,187 @@
+mod aux;
+
+use aux::{card_template, player, SEED};
+use models::v2_0_0::{
+    CardTarget, Effect, Enchantment, PlayerTarget, TargetCondition, TargetSelection, Tier, Tooltip,
+};
+use rand::{rngs::StdRng, SeedableRng};
+use rstest::rstest;
+use simulator::{
+    Card, CardModification, CardTemplate, DispatchableEvent, GlobalCardId, Simulation,
+    SimulationTemplate,
+};
+
+#[rstest]
+#[case(
+    "slow the slowest enemy item for 3 second(s).",
+    Effect::Slow(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Opponent),
+            TargetSelection::HighestCooldown
+        ),
+        3.0
+    )
+)]
+#[case(
+    "haste your fastest item for 2 second(s).",
+    Effect::Haste(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player),
+            TargetSelection::LowestCooldown
+        ),
+        2.0
+    )
+)]
+fn test_ordered_target_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+    assert!(!matches!(
+        Tooltip::try_from(tooltip).unwrap(),
+        Tooltip::Raw(..)
+    ));
+}
+
+#[rstest]
+#[case(TargetSelection::HighestCooldown, |c: &Card| -(c.cooldown.0 as i128))]
+#[case(TargetSelection::LowestCooldown, |c: &Card| c.cooldown.0 as i128)]
+#[case(TargetSelection::HighestValue, |c: &Card| -(c.stats.value as i128))]
+#[case(TargetSelection::Leftmost, |c: &Card| c.position as i128)]
+fn test_slow_follows_selection(#[case] selection: TargetSelection, #[case] key: fn(&Card) -> i128) {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![card_template("Fang", Tier::Bronze)]),
+        opponent: player(vec![
+            card_template("Runic Great Axe", Tier::Silver),
+            card_template("Fang", Tier::Bronze),
+            card_template("Runic Blade", Tier::Gold),
+        ]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+    .with_channel(sender);
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    simulation.cards.get_mut(&fang).unwrap().cooldown_effects = vec![Effect::Slow(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Opponent),
+            selection,
+        ),
+        1.0,
+    )];
+    let best = simulation
+        .opponent
+        .card_ids
+        .iter()
+        .map(|id| key(simulation.cards.get(id).unwrap()))
+        .min()
+        .unwrap();
+    let keys: std::collections::HashMap<GlobalCardId, i128> = simulation
+        .opponent
+        .card_ids
+        .iter()
+        .map(|id| (*id, key(simulation.cards.get(id).unwrap())))
+        .collect();
+
+    simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let slowed: Vec<GlobalCardId> = receiver
+        .try_iter()
+        .filter_map(|event| match event {
+            DispatchableEvent::CardSlowed(summary, ..) => Some(summary.id),
+            _ => None,
+        })
+        .collect();
+    assert!(!slowed.is_empty());
+    assert!(slowed.iter().all(|id| keys[id] == best));
+}
+
+#[rstest]
+#[case(Effect::Slow(
+    CardTarget(
+        3,
+        TargetCondition::HasOwner(PlayerTarget::Opponent),
+        TargetSelection::Random
+    ),
+    1.0
+))]
+#[case(Effect::Freeze(
+    CardTarget(
+        3,
+        TargetCondition::HasOwner(PlayerTarget::Opponent),
+        TargetSelection::Random
+    ),
+    1.0
+))]
+fn test_radiant_and_cooldownless_cards_are_not_slowed_or_frozen(#[case] effect: Effect) {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![card_template("Fang", Tier::Bronze)]),
+        opponent: player(vec![
+            CardTemplate {
+                modifications: vec![CardModification::Enchanted(Enchantment::Radiant)],
+                ..card_template("Fang", Tier::Bronze)
+            },
+            card_template("Bar of Gold", Tier::Bronze),
+            card_template("Fang", Tier::Bronze),
+        ]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+    .with_channel(sender);
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    let target = simulation.opponent.card_ids[2];
+    simulation.cards.get_mut(&fang).unwrap().cooldown_effects = vec![effect];
+
+    simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let affected: Vec<GlobalCardId> = receiver
+        .try_iter()
+        .filter_map(|event| match event {
+            DispatchableEvent::CardSlowed(summary, ..)
+            | DispatchableEvent::CardFrozen(summary, ..) => Some(summary.id),
+            _ => None,
+        })
+        .collect();
+    assert!(!affected.is_empty());
+    assert!(affected.iter().all(|id| *id == target));
+}
+
+#[rstest]
+fn test_haste_skips_cards_without_cooldown() {
+    let (sender, receiver) = std::sync::mpsc::channel();
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Bar of Gold", Tier::Bronze),
+            card_template("Runic Blade", Tier::Gold),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap()
+    .with_channel(sender);
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    let blade = simulation.player.card_ids[2];
+    // Bar of Gold has the lowest cooldown of all, but no cooldown to haste
+    simulation.cards.get_mut(&fang).unwrap().cooldown_effects = vec![Effect::Haste(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf,
+            TargetSelection::LowestCooldown,
+        ),
+        1.0,
+    )];
+
+    simulation.run_once_with_rng(StdRng::seed_from_u64(SEED));
+    let hasted: Vec<GlobalCardId> = receiver
+        .try_iter()
+        .filter_map(|event| match event {
+            DispatchableEvent::CardHasted(summary, ..) => Some(summary.id),
+            _ => None,
+        })
+        .collect();
+    assert!(!hasted.is_empty());
+    assert!(hasted.iter().all(|id| *id == blade));
+}
@@ -0,0 +1,212 @@
+mod aux;
+
+use aux::{card_template, player, run_simulation};
+use models::v2_0_0::{
+    CardTarget, DerivedValue, Effect, EffectEvent, PlayerTarget, Size, TargetCondition,
+    TargetSelection, Tier, Tooltip,
+};
+use rstest::rstest;
+use simulator::{CombatEvent, GlobalCardId, Simulation, SimulationTemplate, TaggedCombatEvent};
+
+fn non_legendary() -> TargetCondition {
+    !TargetCondition::HasStartingTier(Tier::Legendary)
+}
+
+#[rstest]
+#[case(
+    "transform into a silver copy of another small, non-legendary item you have for the fight.",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::TransformIntoCopy(
+        CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player)
+                & !TargetCondition::IsSelf
+                & (TargetCondition::HasSize(Size::Small) & non_legendary()),
+            TargetSelection::Random
+        ),
+        Tier::Silver
+    )))
+)]
+#[case(
+    "transform into a gold copy of the medium, non-legendary item to the left of this for the fight.",
+    Tooltip::When(EffectEvent::OnCooldown(Effect::TransformIntoCopy(
+        CardTarget(
+            1,
+            TargetCondition::AdjacentLeft
+                & (TargetCondition::HasSize(Size::Medium) & non_legendary()),
+            TargetSelection::Random
+        ),
+        Tier::Gold
+    )))
+)]
+fn test_transform_tooltip(#[case] tooltip: &str, #[case] expected: Tooltip) {
+    assert_eq!(Tooltip::try_from(tooltip).unwrap(), expected);
+}
+
+#[rstest]
+fn test_mirror_becomes_copy_of_left_item() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "mirror transform".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Runic Blade", Tier::Gold),
+                card_template("Mirror", Tier::Gold),
+            ]),
+            opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let events = &result.inner_ref().events;
+    let (mirror, at) = events
+        .iter()
+        .enumerate()
+        .find_map(|(at, e)| match e {
+            TaggedCombatEvent(
+                PlayerTarget::Player,
+                CombatEvent::Transformed(id, "Runic Blade", Tier::Gold, _),
+            ) => Some((*id, at)),
+            _ => None,
+        })
+        .unwrap();
+    // The transformation keeps the id and the replay lists what the item became
+    let summary = result
+        .inner_ref()
+        .cards
+        .iter()
+        .find(|card| card.id == mirror)
+        .unwrap();
+    assert_eq!(summary.name, "Runic Blade");
+    assert!(events[at..]
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::DealDamage(.., id) if id == mirror)));
+    assert!(!events
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::Transformed(id, ..) if id != mirror)));
+    Ok(())
+}
+
+#[rstest]
+fn test_transform_keeps_position_and_fires_transform_triggers() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Bronze),
+            card_template("Quicksilver", Tier::Silver),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    let quicksilver: GlobalCardId = simulation.player.card_ids[1];
+    let card = simulation.cards.get_mut(&quicksilver).unwrap();
+    let position = card.position;
+    card.card_transformed_triggers.push((
+        TargetCondition::IsSelf,
+        Effect::Burn(PlayerTarget::Opponent, DerivedValue::Constant(3)),
+    ));
+    // Other cards react through the same trigger path
+    simulation
+        .cards
+        .get_mut(&fang)
+        .unwrap()
+        .card_transformed_triggers
+        .push((
+            TargetCondition::Adjacent,
+            Effect::Burn(PlayerTarget::Opponent, DerivedValue::Constant(5)),
+        ));
+
+    let result = simulation.run_once();
+    let events = &result.inner_ref().events;
+    let transformed = events
+        .iter()
+        .position(|e| matches!(e.1, CombatEvent::Transformed(id, "Fang", Tier::Silver, _) if id == quicksilver))
+        .unwrap();
+    assert!(events[transformed..].iter().any(|e| matches!(
+        e.1,
+        CombatEvent::ApplyBurn(PlayerTarget::Opponent, DerivedValue::Constant(3), id)
+            if id == quicksilver
+    )));
+    assert!(events[transformed..].iter().any(|e| matches!(
+        e.1,
+        CombatEvent::ApplyBurn(PlayerTarget::Opponent, DerivedValue::Constant(5), id)
+            if id == fang
+    )));
+    let card = simulation.cards.get(&quicksilver).unwrap();
+    assert_eq!(card.inner.name, "Fang");
+    assert_eq!(card.tier, Tier::Silver);
+    assert_eq!(card.position, position);
+}
+
+#[rstest]
+fn test_transform_only_copies_items_of_the_same_size() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Katana", Tier::Bronze),
+            card_template("Quicksilver", Tier::Silver),
+            card_template("Fang", Tier::Bronze),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let katana: GlobalCardId = simulation.player.card_ids[0];
+    let quicksilver: GlobalCardId = simulation.player.card_ids[1];
+    simulation
+        .cards
+        .get_mut(&quicksilver)
+        .unwrap()
+        .cooldown_effects = vec![Effect::TransformIntoCopy(
+        CardTarget(1, TargetCondition::Adjacent, TargetSelection::Random),
+        Tier::Silver,
+    )];
+
+    // The medium Katana would overlap the Fang, the small Fang is copied instead
+    let result = simulation.run_once();
+    assert!(result
+        .inner_ref()
+        .events
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::Transformed(id, "Fang", ..) if id == quicksilver)));
+    assert!(!result
+        .inner_ref()
+        .events
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::Transformed(_, "Katana", ..))));
+
+    let medium = simulation.cards[&katana].inner.clone();
+    assert!(simulation
+        .transform_card(&quicksilver, medium, Tier::Silver)
+        .is_err());
+    assert_eq!(simulation.cards[&quicksilver].inner.name, "Fang");
+}
+
+#[rstest]
+fn test_transform_resets_cooldown_and_fight_gains() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fang", Tier::Silver),
+            card_template("Quicksilver", Tier::Silver),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    let fang: GlobalCardId = simulation.player.card_ids[0];
+    let quicksilver: GlobalCardId = simulation.player.card_ids[1];
+    let card = simulation.cards.get_mut(&quicksilver).unwrap();
+    card.cooldown_counter = 100;
+    card.stats.damage += 50;
+    card.stats.shield += 50;
+
+    let inner = simulation.cards[&fang].inner.clone();
+    simulation
+        .transform_card(&quicksilver, inner, Tier::Silver)
+        .unwrap();
+    let copy = &simulation.cards[&quicksilver];
+    assert_eq!(copy.cooldown_counter, 0);
+    assert_eq!(copy.stats.damage, simulation.cards[&fang].stats.damage);
+    assert_eq!(copy.stats.shield, simulation.cards[&fang].stats.shield);
+}
@@ -0,0 +1,88 @@
+mod aux;
+
+use aux::{card_id, card_template, player, run_simulation};
+use models::v2_0_0::Tier;
+use rstest::rstest;
+use simulator::{CombatEvent, SimulationTemplate, MAX_TRIGGERS_PER_CARD_PER_TICK};
+
+#[rstest]
+fn test_enemy_item_use_triggers() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "caltrops".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Caltrops", Tier::Gold)]),
+            opponent: player(vec![card_template("Fang", Tier::Bronze)]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let caltrops = card_id(&result, "Caltrops");
+    let fang = card_id(&result, "Fang");
+    let events = &result.inner_ref().events;
+    let fang_uses = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::CardUsed(id) if id == fang))
+        .count();
+    let caltrops_hits = events
+        .iter()
+        .filter(|e| matches!(e.1, CombatEvent::DealDamage(.., id) if id == caltrops))
+        .count();
+    assert!(fang_uses > 0);
+    assert_eq!(fang_uses, caltrops_hits);
+    Ok(())
+}
+
+#[rstest]
+fn test_own_item_use_does_not_trigger_enemy_reaction() -> Result<(), Box<dyn std::error::Error>> {
+    let result = run_simulation(
+        "caltrops".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Caltrops", Tier::Gold),
+                card_template("Fang", Tier::Bronze),
+            ]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let caltrops = card_id(&result, "Caltrops");
+    assert!(!result
+        .inner_ref()
+        .events
+        .iter()
+        .any(|e| matches!(e.1, CombatEvent::DealDamage(.., id) if id == caltrops)));
+    Ok(())
+}
+
+#[rstest]
+fn test_trigger_chains_terminate() -> Result<(), Box<dyn std::error::Error>> {
+    // Each Yo-Yo fully charges the other whenever it is used, so the chain never ends on its own
+    let result = run_simulation(
+        "yo-yo chain".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Yo-Yo", Tier::Diamond),
+                card_template("Yo-Yo", Tier::Diamond),
+            ]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let most_uses_between_ticks = result
+        .inner_ref()
+        .events
+        .split(|e| matches!(e.1, CombatEvent::Tick(..)))
+        .map(|events| {
+            events
+                .iter()
+                .filter(|e| matches!(e.1, CombatEvent::CardUsed(..)))
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    assert!(most_uses_between_ticks > 2);
+    assert!(most_uses_between_ticks <= 2 * (*MAX_TRIGGERS_PER_CARD_PER_TICK + 2));
+    Ok(())
+}
@@ -0,0 +1,93 @@
+mod aux;
+
+use aux::{card_template, count_uses_of, player, run_simulation};
+use models::v2_0_0::{
+    CardTarget, Effect, PlayerTarget, Tag, TargetCondition, TargetSelection, Tier,
+};
+use rstest::rstest;
+use simulator::{CombatEvent, Simulation, SimulationTemplate, MAX_TRIGGERS_PER_CARD_PER_TICK};
+
+#[rstest]
+#[case(
+    "use this.",
+    Effect::Use(CardTarget(1, TargetCondition::IsSelf, TargetSelection::Random))
+)]
+#[case(
+    "use a property.",
+    Effect::UseCard(CardTarget(
+        1,
+        TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(Tag::Property), TargetSelection::Random))
+)]
+#[case(
+    "use a shield item.",
+    Effect::UseCard(CardTarget(
+        1,
+        TargetCondition::HasOwner(PlayerTarget::Player) & TargetCondition::HasTag(Tag::Shield), TargetSelection::Random))
+)]
+fn test_use_tooltip(#[case] tooltip: &str, #[case] expected: Effect) {
+    assert_eq!(Effect::from_tooltip_str(tooltip), expected);
+}
+
+#[rstest]
+fn test_use_activates_target() -> Result<(), Box<dyn std::error::Error>> {
+    let without_keychain = run_simulation(
+        "fort".to_string(),
+        SimulationTemplate {
+            player: player(vec![card_template("Fort", Tier::Gold)]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    let with_keychain = run_simulation(
+        "fort and keychain".to_string(),
+        SimulationTemplate {
+            player: player(vec![
+                card_template("Fort", Tier::Gold),
+                card_template("Keychain", Tier::Gold),
+            ]),
+            opponent: player(vec![]),
+            seed: None,
+            sandstorm: Default::default(),
+        },
+    )?;
+    assert!(count_uses_of(&with_keychain, "Fort") > count_uses_of(&without_keychain, "Fort"));
+    Ok(())
+}
+
+#[rstest]
+fn test_use_chains_terminate() {
+    let mut simulation = Simulation::try_from(SimulationTemplate {
+        player: player(vec![
+            card_template("Fort", Tier::Gold),
+            card_template("Fort", Tier::Gold),
+        ]),
+        opponent: player(vec![]),
+        seed: None,
+        sandstorm: Default::default(),
+    })
+    .unwrap();
+    // Each fort uses the other one whenever it is used
+    for card in simulation.cards.values_mut() {
+        card.cooldown_effects.push(Effect::UseCard(CardTarget(
+            1,
+            TargetCondition::HasOwner(PlayerTarget::Player) & !TargetCondition::IsSelf,
+            TargetSelection::Random,
+        )));
+    }
+    let result = simulation.run_once();
+    let most_uses_between_ticks = result
+        .inner_ref()
+        .events
+        .split(|e| matches!(e.1, CombatEvent::Tick(..)))
+        .map(|events| {
+            events
+                .iter()
+                .filter(|e| matches!(e.1, CombatEvent::CardUsed(..)))
+                .count()
+        })
+        .max()
+        .unwrap_or(0);
+    assert!(most_uses_between_ticks > 2);
+    assert!(most_uses_between_ticks <= 2 * (*MAX_TRIGGERS_PER_CARD_PER_TICK + 1));
+}